@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::registry::Day;
+use super::solver::Params;
+use super::utils::Result;
+
+/// Spread of the wall times measured over several runs
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let n = samples.len();
+        let median = if n % 2 == 1 { samples[n / 2] } else { (samples[n / 2 - 1] + samples[n / 2]) / 2 };
+        Self{ min: samples[0], median, max: samples[n - 1] }
+    }
+}
+
+/// Timings of one step of a day: `parse`, `part1` or `part2`
+#[derive(Debug)]
+pub struct Stage {
+    pub name: String,
+    pub stats: Stats,
+}
+
+/// Solves the day (or only its `part`) `runs` times and collects timing statistics for every step
+pub fn bench(day: &Day, data: &str, params: &Params, part: Option<u8>, runs: usize) -> Result<Vec<Stage>> {
+    let runs = runs.max(1);
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts = BTreeMap::<u8, Vec<Duration>>::new();
+    for _ in 0..runs {
+        let run = day.solve(data, params, part)?;
+        parse.push(run.parse);
+        run.parts.into_iter().for_each(|p| parts.entry(p.part).or_default().push(p.time));
+    }
+
+    let mut stages = vec![Stage{ name: "parse".to_string(), stats: Stats::new(parse) }];
+    stages.extend(
+        parts
+            .into_iter()
+            .map(|(p, times)| Stage{ name: format!("part{}", p), stats: Stats::new(times) })
+    );
+    Ok(stages)
+}
@@ -0,0 +1,207 @@
+use std::path::PathBuf;
+
+use super::report::Format;
+use super::scaffold::Template;
+use super::solver::Params;
+
+pub const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run <N>              Check the first N days against the answers manifest
+  check [DAYS...]      Check the given days, all by default (e.g. `check 1-10,15,20-25`)
+  test <DAY> <FILE>    Solve a day with a custom input file, `-` reads it from stdin
+  bench [DAYS...]      Solve days several times and print min/median/max times as CSV
+  new-day <DAY>        Create the module, input, example and registry entry of a new day
+  help                 Show this message
+
+Options:
+  --year <YEAR>        Event year, the latest implemented one by default
+  --part <1|2>         Solve only the given part
+  --format <FORMAT>    Output of run, check and test: text (default), json or csv
+  --jobs <N>           Solve days on N threads, 0 uses all cores (default 1)
+  --runs <N>           Number of runs for bench (default 10)
+  --data-dir <DIR>     Directory with inputs (default $AOC_DATA_DIR or ./data)
+  --pattern <PATTERN>  Input file names, {dd} is the zero-padded day and {day} the plain one
+                       (default day{dd}.txt)
+  --param <KEY=VALUE>  Day specific setting, may be repeated (e.g. `area=7..27` and `crossings`
+                       for day 24, `cut` for day 25)
+  --record             Store answers of inputs without expected ones in the manifest
+  --template <KIND>    Input shape of new-day: lines (default), grid or sections
+  -h, --help           Show this message
+
+Exit status is 0 when all answers are correct, 1 when some are wrong or failed
+and 2 on invalid arguments.
+";
+
+/// Last day of an event
+pub const LAST_DAY: u8 = 25;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// First N days
+    Run(u8),
+    /// Given days, all implemented ones when empty
+    Check(Vec<u8>),
+    Test(u8, PathBuf),
+    /// Given days, all implemented ones when empty
+    Bench(Vec<u8>),
+    NewDay(u8),
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub year: Option<u16>,
+    pub part: Option<u8>,
+    pub format: Format,
+    /// 0 means all available cores
+    pub jobs: usize,
+    pub runs: usize,
+    pub data_dir: Option<PathBuf>,
+    pub pattern: Option<String>,
+    pub record: bool,
+    pub template: Option<Template>,
+    pub params: Params,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self{ year: None, part: None, format: Format::Text, jobs: 1, runs: 10, data_dir: None, pattern: None, record: false, template: None, params: Params::new() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cli {
+    pub command: Command,
+    pub options: Options,
+}
+
+fn number<T: std::str::FromStr>(what: &str, s: &str) -> Result<T, String> {
+    s.parse().map_err(|_| format!("expected {} as a number, got `{}`", what, s))
+}
+
+fn day(s: &str) -> Result<u8, String> {
+    match number("day", s)? {
+        d @ 1..=LAST_DAY => Ok(d),
+        d => Err(format!("day {} is out of range 1-{}", d, LAST_DAY)),
+    }
+}
+
+/// Parses a list of days and ranges like `1-10,15,20-25`
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for item in spec.split(',') {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (day(from)?, day(to)?);
+                if from > to {
+                    return Err(format!("empty range `{}`", item));
+                }
+                days.extend(from..=to);
+            }
+            None if item.is_empty() => return Err(format!("empty item in `{}`", spec)),
+            None => days.push(day(item)?),
+        }
+    }
+    Ok(days)
+}
+
+/// Parses the day lists of check and bench, no lists give an empty Vec meaning all days
+fn days_or_all(args: &[String]) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for spec in args {
+        days.extend(parse_days(spec)?);
+    }
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Parses the command line arguments without the executable name
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Cli, String> {
+    let mut options = Options::default();
+    let mut positional = Vec::new();
+    let mut help = false;
+    let mut format = false;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') || arg == "-" {
+            positional.push(arg);
+            continue;
+        }
+
+        let (name, inline) = match arg.split_once('=') {
+            Some((n, v)) => (n.to_string(), Some(v.to_string())),
+            None => (arg.clone(), None),
+        };
+        let mut value = || inline.clone().or_else(|| args.next()).ok_or_else(|| format!("{} expects a value", name));
+        match name.as_str() {
+            "-h" | "--help" => help = true,
+            "--record" => options.record = true,
+            "--year" => options.year = match number("year", &value()?)? {
+                y @ 2015.. => Some(y),
+                y => return Err(format!("there is no event in {}", y)),
+            },
+            "--part" => options.part = match number("part", &value()?)? {
+                p @ (1 | 2) => Some(p),
+                p => return Err(format!("part {} does not exist, expected 1 or 2", p)),
+            },
+            "--format" => {
+                options.format = value()?.parse()?;
+                format = true;
+            }
+            "--jobs" => options.jobs = number("jobs count", &value()?)?,
+            "--runs" => options.runs = match number("runs count", &value()?)? {
+                0 => return Err("runs count must be positive".to_string()),
+                r => r,
+            },
+            "--data-dir" => options.data_dir = Some(PathBuf::from(value()?)),
+            "--pattern" => options.pattern = Some(value()?),
+            "--template" => options.template = Some(value()?.parse()?),
+            "--param" => match value()? {
+                v if v.is_empty() || v.starts_with('=') => return Err(format!("expected KEY=VALUE for --param, got `{}`", v)),
+                v => options.params.insert(&v),
+            },
+            _ => return Err(format!("unknown option `{}`", arg)),
+        }
+    }
+
+    if help {
+        return Ok(Cli{ command: Command::Help, options });
+    }
+
+    let Some((cmd, rest)) = positional.split_first() else {
+        return Err("expected a command: run, check, test, bench, new-day or help".to_string());
+    };
+    let command = match (cmd.as_str(), rest) {
+        ("run", [n]) => Command::Run(day(n).map_err(|_| format!("expected days count in range 1-{}, got `{}`", LAST_DAY, n))?),
+        ("run", _) => return Err("run expects the number of days".to_string()),
+        ("check", days) => Command::Check(days_or_all(days)?),
+        ("bench", days) => Command::Bench(days_or_all(days)?),
+        ("test", [d, path]) => Command::Test(day(d)?, PathBuf::from(path)),
+        ("test", _) => return Err("test expects a day and an input file".to_string()),
+        ("new-day", [d]) => Command::NewDay(day(d)?),
+        ("new-day", _) => return Err("new-day expects a day".to_string()),
+        ("help", []) => Command::Help,
+        (c, _) if ["run", "check", "bench", "test", "new-day", "help"].contains(&c) => {
+            return Err(format!("unexpected arguments for {}", c))
+        }
+        (c, _) => return Err(format!("unknown command `{}`, expected run, check, test, bench, new-day or help", c)),
+    };
+
+    if options.record && !matches!(command, Command::Run(_) | Command::Check(_)) {
+        return Err("--record can only be used with run and check".to_string());
+    }
+    if format && !matches!(command, Command::Run(_) | Command::Check(_) | Command::Test(..)) {
+        return Err("--format can only be used with run, check and test".to_string());
+    }
+    if options.params != Params::default() && matches!(command, Command::NewDay(_) | Command::Help) {
+        return Err("--param can only be used with run, check, test and bench".to_string());
+    }
+    if options.template.is_some() && !matches!(command, Command::NewDay(_)) {
+        return Err("--template can only be used with new-day".to_string());
+    }
+    Ok(Cli{ command, options })
+}
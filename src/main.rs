@@ -1,110 +1,258 @@
-use std::collections::VecDeque;
 use std::env;
 use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-use aoc2023::utils::Result;
-
-
-fn run_day(day: u8, path: String, check: bool) -> Result {
-    let input = fs::read_to_string(&path);
-    let res = match input {
-        Ok(data) => {
-            let data = data.trim_end();
-            let day_func = match day {
-                1 => aoc2023::day01::run,
-                2 => aoc2023::day02::run,
-                3 => aoc2023::day03::run,
-                4 => aoc2023::day04::run,
-                5 => aoc2023::day05::run,
-                6 => aoc2023::day06::run,
-                7 => aoc2023::day07::run,
-                8 => aoc2023::day08::run,
-                9 => aoc2023::day09::run,
-                10 => aoc2023::day10::run,
-                11 => aoc2023::day11::run,
-                12 => aoc2023::day12::run,
-                13 => aoc2023::day13::run,
-                14 => aoc2023::day14::run,
-                15 => aoc2023::day15::run,
-                16 => aoc2023::day16::run,
-                17 => aoc2023::day17::run,
-                18 => aoc2023::day18::run,
-                19 => aoc2023::day19::run,
-                20 => aoc2023::day20::run,
-                21 => aoc2023::day21::run,
-                22 => aoc2023::day22::run,
-                23 => aoc2023::day23::run,
-                24 => aoc2023::day24::run,
-                25 => aoc2023::day25::run,
-                _ => unreachable!(),
-            };
-            day_func(data, check)
+use aoc::bench;
+use aoc::cli::{self, Cli, Command, Options};
+use aoc::pool;
+use aoc::manifest::{self, DataDir, Expected, Manifest};
+use aoc::registry::{self, Day};
+use aoc::report::{Format, Printer, Report, Status};
+use aoc::scaffold;
+use aoc::solver::Params;
+use aoc::utils::{Error, Result};
+
+const DATA_DIR: &str = "./data";
+const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Input root: `--data-dir`, then `$AOC_DATA_DIR`, then `./data`, falling back to the `data`
+/// directory of the crate itself so the binary works from any working directory
+fn data_root(arg: Option<PathBuf>) -> PathBuf {
+    arg.or_else(|| env::var_os(DATA_DIR_ENV).map(PathBuf::from))
+        .unwrap_or_else(|| {
+            let local = PathBuf::from(DATA_DIR);
+            if local.is_dir() { local } else { Path::new(env!("CARGO_MANIFEST_DIR")).join("data") }
+        })
+}
+
+/// Reads an input file, `-` stands for stdin
+fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut data = String::new();
+        io::stdin().read_to_string(&mut data).map_err(|e| Error::io("<stdin>", e))?;
+        Ok(data)
+    } else {
+        fs::read_to_string(path).map_err(|e| Error::io(path, e))
+    }
+}
+
+fn run_day(entry: &Day, name: &str, path: &Path, params: &Params, part: Option<u8>, expected: Option<&Expected>) -> Report {
+    match read_input(path) {
+        Ok(data) => Report::solve(entry, name, data.trim_end(), params, part, expected),
+        Err(e) => Report::failed(entry.year, entry.day, name, e),
+    }
+}
+
+enum Task {
+    Done(Report),
+    Solve{ entry: &'static Day, name: String, path: PathBuf, expected: Option<Expected> },
+}
+
+/// Runs every input of the given days against the answers manifest on `jobs` threads
+/// and returns the number of errors. Reports are printed in order of days.
+fn check_days<W: Write>(
+    data: &DataDir, year: u16, days: impl Iterator<Item = u8>, options: &Options, jobs: usize, out: &mut Printer<W>
+) -> Result<usize> {
+    let Options{ params, part, record, .. } = options;
+    let manifest_path = data.manifest();
+    let mut manifest = Manifest::load(&manifest_path).map_err(|e| Error::io(&manifest_path, e))?;
+    let mut recorded = false;
+
+    let mut tasks = Vec::new();
+    for day in days {
+        let default_name = format!("day{:02}", day);
+        let Some(entry) = registry::find(year, day) else {
+            tasks.push(Task::Done(Report::failed(year, day, &default_name, format!("day {} is not implemented", day))));
+            continue;
+        };
+        // e.g. `--part 2` for the last day of an event, only `test` fails on it
+        if let (false, Some(p)) = (entry.has_part(*part), part) {
+            tasks.push(Task::Done(Report::skipped(year, day, &default_name, *p)));
+            continue;
+        }
+        let inputs = data.inputs(day).map_err(|e| Error::io(&data.root, e))?;
+        if inputs.is_empty() {
+            let msg = format!("no input for day {} in {}", day, data.root.join(data.file_name(day)).display());
+            tasks.push(Task::Done(Report::failed(year, day, &default_name, msg)));
+            continue;
         }
-        Err(_) => {
-            panic!("ERROR: Can not read data from {}", path);
+        for (name, path) in inputs {
+            let expected = manifest.get(&name).cloned();
+            tasks.push(Task::Solve{ entry, name, path, expected });
         }
+    }
+
+    let solve = |task| match task {
+        Task::Done(report) => report,
+        Task::Solve{ entry, name, path, expected } => run_day(entry, &name, &path, params, *part, expected.as_ref()),
     };
 
-    match res {
-        Ok(_) => {Ok(())},
-        Err(_) => {
-            println!("ERROR: wrong answer at day {}", day);
-            Err(())
-        },
+    let mut nerr = 0;
+    pool::map_ordered(tasks, jobs, solve, |mut report: Report| {
+        let missing = report.parts
+            .iter()
+            .filter(|p| p.status == Status::Unchecked)
+            .map(|p| (p.part, p.answer.clone()))
+            .collect::<Vec<_>>();
+        if report.is_ok() && !missing.is_empty() {
+            if *record {
+                let e = manifest.entry(&report.input);
+                missing.into_iter().for_each(|(part, ans)| e.set_part(part, ans));
+                report.notes.push(format!("Recorded answers for {}", report.input));
+                recorded = true;
+            } else {
+                report.notes.push(format!("No expected answers for {}, use --record to store them", report.input));
+            }
+        }
+        if !report.is_ok() {
+            nerr += 1;
+        }
+        out.print(&report).map_err(|e| Error::io("<stdout>", e))
+    })?;
+
+    if recorded {
+        manifest.save(&manifest_path).map_err(|e| Error::io(&manifest_path, e))?;
     }
+    Ok(nerr)
 }
 
-fn main() -> Result {
-    let mut args: VecDeque<String> = env::args().collect();
-    let _ = args.pop_front();   // skip executable filename
-
-    let cmd = args.pop_front().expect("Command argument expected: run|check|test");
-    match cmd.as_str() {
-        "run" => {      // run first N days
-            let count: u8 = args.pop_front()
-                .expect("Expected days count but got nothing")
-                .parse()
-                .expect("Expected days count as a number");
-            let mut nerr = 0;
-            for day in 1..=count {
-                println!("Day {}:", day);
-                let path = format!("./data/day{:02}.txt", day);
-                let day_result = run_day(day, path, true);
-                if day_result.is_err() {
-                    nerr += 1;
+/// Solves the main input of every given day `runs` times and prints min/median/max times as CSV
+fn bench_days(
+    data: &DataDir, year: u16, days: impl Iterator<Item = u8>, params: &Params, part: Option<u8>, runs: usize
+) -> Result<bool> {
+    let mut ok = true;
+
+    println!("year,day,stage,runs,min_ns,median_ns,max_ns");
+    for day in days {
+        let Some(entry) = registry::find(year, day) else {
+            eprintln!("ERROR: day {} is not implemented", day);
+            ok = false;
+            continue;
+        };
+        if let (false, Some(p)) = (entry.has_part(part), part) {
+            eprintln!("Day {} has no part {}, skipped", day, p);
+            continue;
+        }
+        let input = match read_input(&data.root.join(data.file_name(day))) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("ERROR: day {}: {}", day, e);
+                ok = false;
+                continue;
+            }
+        };
+
+        match bench::bench(entry, input.trim_end(), params, part, runs) {
+            Ok(stages) => {
+                for s in stages {
+                    println!(
+                        "{},{},{},{},{},{},{}",
+                        year, day, s.name, runs, s.stats.min.as_nanos(), s.stats.median.as_nanos(), s.stats.max.as_nanos()
+                    );
                 }
-                println!();
             }
-            println!("==============================");
-            if nerr > 0 {
-                println!("ERRORS: {}", nerr);
-            } else {
-                println!("OK");
+            Err(e) => {
+                eprintln!("ERROR: day {}: {}", day, e);
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+fn run(cli: Cli, year: u16, data: DataDir) -> Result<bool> {
+    let Cli{ command, options } = cli;
+    let format = options.format;
+    let mut out = Printer::new(format, io::stdout().lock());
+    let stdout_err = |e| Error::io("<stdout>", e);
+    let jobs = match options.jobs {
+        0 => pool::available_jobs(),
+        j => j,
+    };
+
+    let implemented = || registry::year(year).into_iter().flat_map(|y| y.days).map(|d| d.day);
+    let days_or_all = |days: Vec<u8>| if days.is_empty() { implemented().collect() } else { days };
+
+    match command {
+        Command::Run(count) => {
+            let days = implemented().take_while(|&d| d <= count);
+            out.begin().map_err(stdout_err)?;
+            let nerr = check_days(&data, year, days, &options, jobs, &mut out)?;
+            out.end().map_err(stdout_err)?;
+            if format == Format::Text {
+                println!("==============================");
+                if nerr > 0 {
+                    println!("ERRORS: {}", nerr);
+                } else {
+                    println!("OK");
+                }
             }
+            Ok(nerr == 0)
         }
-        "check" => {    // check all days or specified ones
-            let days: Vec<u8> = match args.len() {
-                0 => (1..=25).collect(),
-                _ => args.iter().map(|d| d.parse().expect("Expected day number as a number")).collect(),
+        Command::Check(days) => {
+            out.begin().map_err(stdout_err)?;
+            let nerr = check_days(&data, year, days_or_all(days).into_iter(), &options, jobs, &mut out)?;
+            out.end().map_err(stdout_err)?;
+            Ok(nerr == 0)
+        }
+        Command::Bench(days) => bench_days(&data, year, days_or_all(days).into_iter(), &options.params, options.part, options.runs),
+        Command::Test(day, path) => {
+            let entry = registry::find(year, day)
+                .ok_or_else(|| Error::unsolvable(format!("day {} of {} is not implemented", day, year)))?;
+            let name = match path.file_stem() {
+                _ if path == Path::new("-") => "stdin".to_string(),
+                Some(s) => s.to_string_lossy().into_owned(),
+                None => String::new(),
             };
-            for day in days {
-                println!("Day {}:", day);
-                let path = format!("./data/day{:02}.txt", day);
-                let _ = run_day(day, path, true);
-                println!();
+            let report = run_day(entry, &name, &path, &options.params, options.part, None);
+            out.begin().and_then(|_| out.print(&report)).and_then(|_| out.end()).map_err(stdout_err)?;
+            Ok(report.is_ok())
+        }
+        Command::NewDay(day) => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+            for path in scaffold::new_day(root, &data, year, day, options.template.unwrap_or_default())? {
+                println!("Wrote {}", path.strip_prefix(root).unwrap_or(&path).display());
             }
-        },
-        "test" => {     // test day specified with custom input file
-            let day: u8 = args.pop_front()
-                .expect("Expected day number but got nothing")
-                .parse()
-                .expect("Expected day number as a number");
-            let path = args.pop_front().expect("Expected day faile path but got nothing");
-            let _ = run_day(day, path, false);
-        },
-        _ => panic!("Unknown command: {}. Supported commands: run|check|test", cmd)
+            Ok(true)
+        }
+        Command::Help => {
+            print!("{}", cli::USAGE);
+            Ok(true)
+        }
     }
+}
+
+/// Invalid arguments, as opposed to failed or wrong answers
+const USAGE_ERROR: u8 = 2;
 
+fn main() -> ExitCode {
+    let usage_error = |e: String| {
+        eprintln!("error: {}\n\nRun with --help for usage", e);
+        ExitCode::from(USAGE_ERROR)
+    };
+    let cli = match cli::parse(env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(e) => return usage_error(e),
+    };
+    let year = cli.options.year.unwrap_or(registry::latest().year);
+    if registry::year(year).is_none() && !matches!(cli.command, Command::NewDay(_) | Command::Help) {
+        return usage_error(format!("no days of {} are implemented", year));
+    }
+    let root = data_root(cli.options.data_dir.clone());
+    let pattern = cli.options.pattern.as_deref().unwrap_or(manifest::DEFAULT_PATTERN);
+    let data = match DataDir::new(root, pattern) {
+        Ok(data) => data.year(year),
+        Err(e) => return usage_error(e),
+    };
 
-    Ok(())
+    match run(cli, year, data) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("ERROR: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,250 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::solver::Answer;
+
+const HEADER: &str = "\
+# Expected answers, one table per input file stored next to this manifest.
+# The table name is the file name without extension: [day05] is checked against
+# day05.txt, [day05-alice] against day05-alice.txt.
+";
+
+/// Expected answers for a single input file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    /// Parts that do not apply to the input (e.g. an example written for the other part)
+    pub skip: Vec<u8>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set_part(&mut self, part: u8, answer: Answer) {
+        match part {
+            1 => self.part1 = Some(answer),
+            2 => self.part2 = Some(answer),
+            _ => unreachable!("puzzles have two parts at most"),
+        }
+    }
+}
+
+/// Answers manifest: a small TOML subset with one table per input file
+///
+/// ```toml
+/// [day05]
+/// part1 = 462648396
+/// part2 = 2520479
+///
+/// [day08_t1]
+/// part1 = 2
+/// skip = "part2"
+/// ```
+#[derive(Debug, Default)]
+pub struct Manifest {
+    entries: BTreeMap<String, Expected>,
+}
+
+impl Manifest {
+    /// Loads the manifest, a missing file is treated as an empty one
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = BTreeMap::<String, Expected>::new();
+        let mut current: Option<String> = None;
+
+        for (i, raw) in text.lines().enumerate() {
+            let line = strip_comment(raw).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let name = name.trim().trim_matches('"').to_string();
+                if entries.contains_key(&name) {
+                    return Err(format!("line {}: duplicate table [{}]", i + 1, name));
+                }
+                entries.insert(name.clone(), Expected::default());
+                current = Some(name);
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected `key = value`", i + 1));
+            };
+            let Some(name) = &current else {
+                return Err(format!("line {}: value outside of a table", i + 1));
+            };
+
+            let value = value.trim();
+            if key.trim() == "skip" {
+                let expected = entries.get_mut(name).unwrap();
+                for part in value.trim_matches('"').split(',') {
+                    match part.trim() {
+                        "part1" => expected.skip.push(1),
+                        "part2" => expected.skip.push(2),
+                        p => return Err(format!("line {}: unknown part `{}`", i + 1, p)),
+                    }
+                }
+                continue;
+            }
+
+            let answer = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(s) => Answer::from(s),
+                None => match value.parse::<i128>() {
+                    Ok(v) => Answer::from(v),
+                    Err(_) => return Err(format!("line {}: invalid answer `{}`", i + 1, value)),
+                },
+            };
+
+            let expected = entries.get_mut(name).unwrap();
+            match key.trim() {
+                "part1" => expected.part1 = Some(answer),
+                "part2" => expected.part2 = Some(answer),
+                k => return Err(format!("line {}: unknown key `{}`", i + 1, k)),
+            }
+        }
+
+        Ok(Self{ entries })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Expected> {
+        self.entries.get(name)
+    }
+
+    pub fn entry(&mut self, name: &str) -> &mut Expected {
+        self.entries.entry(name.to_string()).or_default()
+    }
+}
+
+impl std::fmt::Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", HEADER)?;
+        for (name, expected) in self.entries.iter() {
+            writeln!(f, "\n[{}]", name)?;
+            for (key, answer) in [("part1", &expected.part1), ("part2", &expected.part2)] {
+                match answer {
+                    Some(Answer::Str(s)) => writeln!(f, "{} = \"{}\"", key, s)?,
+                    Some(a) => writeln!(f, "{} = {}", key, a)?,
+                    None => {}
+                }
+            }
+            if !expected.skip.is_empty() {
+                let parts = expected.skip.iter().map(|p| format!("part{}", p)).collect::<Vec<_>>();
+                writeln!(f, "skip = \"{}\"", parts.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Part of the line before a `#` comment, a `#` inside a quoted string belongs to the value
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Day number encoded in an input name: `day05`, `day05-alice` or `day05_t1`
+pub fn day_of(name: &str) -> Option<u8> {
+    let rest = name.strip_prefix("day")?;
+    let digits = rest.get(..2)?;
+    let suffix = &rest[2..];
+    if !(suffix.is_empty() || suffix.starts_with('-') || suffix.starts_with('_')) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Default name of the input files, `{dd}` is the zero-padded day number and `{day}` the plain one
+pub const DEFAULT_PATTERN: &str = "day{dd}.txt";
+
+/// Name of the answers manifest kept next to the inputs
+pub const MANIFEST: &str = "answers.toml";
+
+/// Directory with puzzle inputs and the pattern their file names follow.
+///
+/// Every year has its own subdirectory with the inputs and the answers manifest (`2023/day05.txt`).
+/// Besides the main input (`day05.txt`) a day can have extra ones with a `-` or `_` suffix
+/// (`day05-alice.txt`), each of them is named in the manifest by its file stem.
+#[derive(Debug, Clone)]
+pub struct DataDir {
+    pub root: PathBuf,
+    pub pattern: String,
+}
+
+impl DataDir {
+    pub fn new(root: impl Into<PathBuf>, pattern: &str) -> Result<Self, String> {
+        if !pattern.contains("{dd}") && !pattern.contains("{day}") {
+            return Err(format!("input pattern `{}` must contain {{dd}} or {{day}}", pattern));
+        }
+        Ok(Self{ root: root.into(), pattern: pattern.to_string() })
+    }
+
+    /// Inputs of one event, kept in a subdirectory named after the year
+    pub fn year(&self, year: u16) -> Self {
+        Self{ root: self.root.join(year.to_string()), pattern: self.pattern.clone() }
+    }
+
+    pub fn manifest(&self) -> PathBuf {
+        self.root.join(MANIFEST)
+    }
+
+    /// File name of the main input of `day`
+    pub fn file_name(&self, day: u8) -> String {
+        self.pattern.replace("{dd}", &format!("{:02}", day)).replace("{day}", &day.to_string())
+    }
+
+    /// Names and paths of all input files for `day`, sorted by name
+    pub fn inputs(&self, day: u8) -> io::Result<Vec<(String, PathBuf)>> {
+        let main = PathBuf::from(self.file_name(day));
+        let (Some(stem), ext) = (main.file_stem().and_then(|s| s.to_str()), main.extension()) else {
+            return Ok(Vec::new());
+        };
+
+        let mut res = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let path = entry?.path();
+            if !path.is_file() || path.extension() != ext {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                let suffix = name.strip_prefix(stem);
+                if suffix.is_some_and(|s| s.is_empty() || s.starts_with('-') || s.starts_with('_')) {
+                    res.push((name.to_string(), path.clone()));
+                }
+            }
+        }
+        res.sort();
+        Ok(res)
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+/// Number of threads to use when `--jobs 0` asks for all of them
+pub fn available_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Applies `f` to every item on `jobs` threads and hands the results to `sink` in the order of `items`.
+/// Stops at the first error returned by `sink`.
+pub fn map_ordered<T, R, E, F, S>(items: Vec<T>, jobs: usize, f: F, mut sink: S) -> Result<(), E>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+    S: FnMut(R) -> Result<(), E>,
+{
+    let jobs = jobs.clamp(1, items.len().max(1));
+    if jobs == 1 {
+        return items.into_iter().try_for_each(|item| sink(f(item)));
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|s| {
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let (queue, f) = (&queue, &f);
+            s.spawn(move || loop {
+                let Some((i, item)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                if tx.send((i, f(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        for (i, r) in rx {
+            pending.insert(i, r);
+            while let Some(r) = pending.remove(&next) {
+                sink(r)?;
+                next += 1;
+            }
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+use super::solver::{Answer, DynSolver, Params, Solver};
+use super::utils::{Error, Result};
+
+/// Registry entry describing one implemented day
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: u8,
+    parse: fn(&str, &Params) -> Result<Box<dyn DynSolver>>,
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+    /// See [`Solver::details`]
+    pub details: Vec<String>,
+}
+
+/// Answers to the solved parts of a day and the wall time spent on parsing and on each part
+#[derive(Debug)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+fn parse_boxed<S: Solver + 'static>(data: &str, params: &Params) -> Result<Box<dyn DynSolver>> {
+    Ok(Box::new(S::parse_with(data, params)?))
+}
+
+impl Day {
+    pub const fn of<S: Solver + 'static>(year: u16) -> Self {
+        Self {
+            year,
+            day: S::DAY,
+            title: S::TITLE,
+            parts: S::PARTS,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    pub fn parse(&self, data: &str) -> Result<Box<dyn DynSolver>> {
+        self.parse_with(data, &Params::default())
+    }
+
+    pub fn parse_with(&self, data: &str, params: &Params) -> Result<Box<dyn DynSolver>> {
+        (self.parse)(data, params)
+    }
+
+    /// Whether the day has `part`, no part stands for all of them
+    pub fn has_part(&self, part: Option<u8>) -> bool {
+        part.is_none_or(|p| (1..=self.parts).contains(&p))
+    }
+
+    /// Parses the input and returns the answers to all parts of the day, or only to `part`
+    pub fn solve(&self, data: &str, params: &Params, part: Option<u8>) -> Result<Run> {
+        if let (false, Some(p)) = (self.has_part(part), part) {
+            return Err(Error::unsolvable(format!("day {} has no part {}", self.day, p)));
+        }
+
+        let start = Instant::now();
+        let solver = self.parse_with(data, params)?;
+        let parse = start.elapsed();
+
+        let mut parts = Vec::new();
+        for p in (1..=self.parts).filter(|&p| part.is_none_or(|part| part == p)) {
+            let start = Instant::now();
+            let answer = if p == 1 { solver.part1()? } else { solver.part2()? };
+            let time = start.elapsed();
+            parts.push(PartRun{ part: p, answer, time, details: solver.details(p) });
+        }
+        Ok(Run{ parse, parts })
+    }
+}
+
+/// Days implemented for one event
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// Looks up a registered year
+pub fn year(year: u16) -> Option<&'static Year> {
+    crate::YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent registered year
+pub fn latest() -> &'static Year {
+    crate::YEARS.last().expect("no years are registered")
+}
+
+/// Looks up a registered day by its year and number
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    self::year(year)?.days.iter().find(|d| d.day == day)
+}
+
+/// Declares day modules of a year and registers their `Puzzle` solvers in its `DAYS`,
+/// the year number is taken from the `YEAR` constant of the calling module
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every implemented day, in order
+        pub const DAYS: &[$crate::registry::Day] = &[
+            $($crate::registry::Day::of::<$module::Puzzle>(YEAR),)*
+        ];
+    };
+}
+
+/// Declares year modules and registers them in [`crate::YEARS`]
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every year with implemented days, in order
+        pub const YEARS: &[registry::Year] = &[
+            $(registry::Year{ year: $module::YEAR, days: $module::DAYS },)*
+        ];
+    };
+}
@@ -0,0 +1,291 @@
+use std::fmt::{self, Write as _};
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::manifest::Expected;
+use super::registry::{Day, PartRun};
+use super::solver::{Answer, Params};
+use super::utils::Error;
+
+/// Output format of the runner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("unknown format `{}`, expected text, json or csv", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Wrong,
+    /// There is no expected answer to compare with
+    Unchecked,
+    /// The manifest marks the part as not applicable to the input, or the day has no selected part
+    Skipped,
+    Error,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Wrong => "wrong",
+            Self::Unchecked => "unchecked",
+            Self::Skipped => "skipped",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub expected: Option<Answer>,
+    pub status: Status,
+    pub time: Duration,
+    pub details: Vec<String>,
+}
+
+/// Outcome of solving one input file of a day
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub parse: Option<Duration>,
+    pub parts: Vec<PartReport>,
+    pub error: Option<String>,
+    /// Remarks shown in the text format only, e.g. about recorded answers
+    pub notes: Vec<String>,
+}
+
+impl Report {
+    pub fn new(year: u16, day: u8, input: &str) -> Self {
+        Self{ year, day, input: input.to_string(), parse: None, parts: Vec::new(), error: None, notes: Vec::new() }
+    }
+
+    pub fn failed(year: u16, day: u8, input: &str, e: impl fmt::Display) -> Self {
+        Self{ error: Some(e.to_string()), ..Self::new(year, day, input) }
+    }
+
+    /// Day left out because it has no `part`, reported with a note instead of an error
+    pub fn skipped(year: u16, day: u8, input: &str, part: u8) -> Self {
+        Self{ notes: vec![format!("Day {} has no part {}, skipped", day, part)], ..Self::new(year, day, input) }
+    }
+
+    /// Solves `data` (all parts or only `part`) and compares the answers with `expected`
+    pub fn solve(entry: &Day, input: &str, data: &str, params: &Params, part: Option<u8>, expected: Option<&Expected>) -> Self {
+        let run = match entry.solve(data, params, part) {
+            Ok(run) => run,
+            Err(e) => return Self::failed(entry.year, entry.day, input, e),
+        };
+
+        let mut report = Self{ parse: Some(run.parse), ..Self::new(entry.year, entry.day, input) };
+        for PartRun{ part, answer, time, details } in run.parts {
+            let exp = expected.and_then(|e| e.part(part)).cloned();
+            let status = match &exp {
+                Some(e) if *e == answer => Status::Ok,
+                Some(e) => {
+                    report.error.get_or_insert_with(|| {
+                        Error::WrongAnswer{ day: entry.day, part, expected: e.clone(), actual: answer.clone() }.to_string()
+                    });
+                    Status::Wrong
+                }
+                None if expected.is_some_and(|e| e.skip.contains(&part)) => Status::Skipped,
+                None => Status::Unchecked,
+            };
+            report.parts.push(PartReport{ part, answer, expected: exp, status, time, details });
+        }
+        report
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn text(&self) -> String {
+        let mut s = String::new();
+        if self.input == format!("day{:02}", self.day) {
+            let _ = writeln!(s, "Day {}:", self.day);
+        } else {
+            let _ = writeln!(s, "Day {} ({}):", self.day, self.input);
+        }
+        if let Some(t) = self.parse {
+            let _ = writeln!(s, "Parse: {:.2?}", t);
+        }
+        for p in self.parts.iter() {
+            match (&p.expected, p.status) {
+                (Some(e), Status::Wrong) => { let _ = writeln!(s, "Part{}: {} (expected {}) [{:.2?}]", p.part, p.answer, e, p.time); },
+                _ => { let _ = writeln!(s, "Part{}: {} [{:.2?}]", p.part, p.answer, p.time); },
+            }
+            for d in p.details.iter() {
+                let _ = writeln!(s, "  {}", d);
+            }
+        }
+        if let Some(e) = &self.error {
+            let _ = writeln!(s, "ERROR: {}", e);
+        }
+        for n in self.notes.iter() {
+            let _ = writeln!(s, "{}", n);
+        }
+        s
+    }
+
+    /// Status of a report without parts: an unsolved input or a day skipped as a whole
+    fn status(&self) -> Status {
+        if self.error.is_some() { Status::Error } else { Status::Skipped }
+    }
+
+    /// One record per part, or a single one without a part when the input was not solved
+    fn records(&self) -> Vec<[(&'static str, Value); 11]> {
+        let record = |p: Option<&PartReport>| [
+            ("year", Value::Int(self.year as i128)),
+            ("day", Value::Int(self.day as i128)),
+            ("input", Value::Str(self.input.clone())),
+            ("part", p.map_or(Value::Null, |p| Value::Int(p.part as i128))),
+            ("answer", p.map_or(Value::Null, |p| Value::answer(&p.answer))),
+            ("expected", p.and_then(|p| p.expected.as_ref()).map_or(Value::Null, Value::answer)),
+            ("status", Value::Str(p.map_or(self.status(), |p| p.status).as_str().to_string())),
+            ("time_ns", p.map_or(Value::Null, |p| Value::Int(p.time.as_nanos() as i128))),
+            ("parse_ns", self.parse.map_or(Value::Null, |t| Value::Int(t.as_nanos() as i128))),
+            ("error", match p {
+                Some(p @ PartReport{ status: Status::Wrong, expected: Some(e), .. }) => Value::Str(
+                    Error::WrongAnswer{ day: self.day, part: p.part, expected: e.clone(), actual: p.answer.clone() }.to_string()
+                ),
+                Some(_) => Value::Null,
+                None => self.error.clone().map_or(Value::Null, Value::Str),
+            }),
+            ("details", match p {
+                Some(p) if !p.details.is_empty() => Value::Str(p.details.join("\n")),
+                _ => Value::Null,
+            }),
+        ];
+
+        if self.parts.is_empty() {
+            vec![record(None)]
+        } else {
+            self.parts.iter().map(|p| record(Some(p))).collect()
+        }
+    }
+}
+
+enum Value {
+    Null,
+    Int(i128),
+    Str(String),
+}
+
+impl Value {
+    fn answer(a: &Answer) -> Self {
+        match a {
+            Answer::Int(v) => Self::Int(*v as i128),
+            Answer::BigInt(v) => Self::Int(*v),
+            Answer::Str(s) => Self::Str(s.clone()),
+        }
+    }
+
+    fn json(&self) -> String {
+        match self {
+            Self::Null => "null".to_string(),
+            Self::Int(v) => v.to_string(),
+            Self::Str(s) => {
+                let mut res = String::from("\"");
+                for c in s.chars() {
+                    match c {
+                        '"' => res.push_str("\\\""),
+                        '\\' => res.push_str("\\\\"),
+                        '\n' => res.push_str("\\n"),
+                        c if (c as u32) < 0x20 => { let _ = write!(res, "\\u{:04x}", c as u32); },
+                        c => res.push(c),
+                    }
+                }
+                res.push('"');
+                res
+            }
+        }
+    }
+
+    fn csv(&self) -> String {
+        match self {
+            Self::Null => String::new(),
+            Self::Int(v) => v.to_string(),
+            Self::Str(s) if s.contains([',', '"', '\n']) => format!("\"{}\"", s.replace('"', "\"\"")),
+            Self::Str(s) => s.clone(),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "year,day,input,part,answer,expected,status,time_ns,parse_ns,error,details";
+
+/// Streams reports to `out` in the chosen format
+pub struct Printer<W: Write> {
+    format: Format,
+    out: W,
+    count: usize,
+}
+
+impl<W: Write> Printer<W> {
+    pub fn new(format: Format, out: W) -> Self {
+        Self{ format, out, count: 0 }
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn begin(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Text => Ok(()),
+            Format::Json => writeln!(self.out, "["),
+            Format::Csv => writeln!(self.out, "{}", CSV_HEADER),
+        }
+    }
+
+    pub fn print(&mut self, report: &Report) -> io::Result<()> {
+        match self.format {
+            Format::Text => writeln!(self.out, "{}", report.text())?,
+            Format::Json => {
+                for r in report.records() {
+                    let fields = r.iter().map(|(k, v)| format!("\"{}\": {}", k, v.json())).collect::<Vec<_>>();
+                    if self.count > 0 {
+                        writeln!(self.out, ",")?;
+                    }
+                    write!(self.out, "  {{{}}}", fields.join(", "))?;
+                    self.count += 1;
+                }
+            }
+            Format::Csv => {
+                for r in report.records() {
+                    let fields = r.iter().map(|(_, v)| v.csv()).collect::<Vec<_>>();
+                    writeln!(self.out, "{}", fields.join(","))?;
+                }
+            }
+        }
+        self.out.flush()
+    }
+
+    pub fn end(&mut self) -> io::Result<()> {
+        match self.format {
+            Format::Json if self.count > 0 => writeln!(self.out, "\n]"),
+            Format::Json => writeln!(self.out, "]"),
+            _ => Ok(()),
+        }
+    }
+}
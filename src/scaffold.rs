@@ -0,0 +1,141 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::manifest::{DataDir, MANIFEST};
+use super::utils::{Error, Result};
+
+/// Shape of the input a new day is parsed as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Template {
+    /// One item per line
+    #[default]
+    Lines,
+    /// Rectangular grid of characters
+    Grid,
+    /// Blocks of lines separated by empty ones
+    Sections,
+}
+
+impl FromStr for Template {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "lines" => Ok(Self::Lines),
+            "grid" => Ok(Self::Grid),
+            "sections" => Ok(Self::Sections),
+            _ => Err(format!("unknown template `{}`, expected lines, grid or sections", s)),
+        }
+    }
+}
+
+impl Template {
+    pub fn source(&self, day: u8) -> String {
+        let text = match self {
+            Self::Lines => include_str!("../templates/lines.rs.template"),
+            Self::Grid => include_str!("../templates/grid.rs.template"),
+            Self::Sections => include_str!("../templates/sections.rs.template"),
+        };
+        text.replace("__DAY__", &day.to_string())
+    }
+}
+
+/// Adds `module` to the `list` macro (`days!` or `years!`) of the module source `text` at `path`,
+/// keeping the list sorted
+fn register(path: &Path, text: &str, list: &str, module: &str) -> Result<String> {
+    let error = |kind, msg| Error::io(path, io::Error::new(kind, msg));
+    let open = format!("{} {{", list);
+    let start = text.find(&open).ok_or_else(|| error(io::ErrorKind::InvalidData, format!("no `{}` list", list)))?;
+    let end = text[start..].find("\n}").map(|i| start + i)
+        .ok_or_else(|| error(io::ErrorKind::InvalidData, format!("unterminated `{}` list", list)))?;
+    let mut modules = text[start + open.len()..end]
+        .lines()
+        .map(|l| l.trim().trim_end_matches(','))
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+    if modules.contains(&module) {
+        return Err(error(io::ErrorKind::AlreadyExists, format!("{} is already registered", module)));
+    }
+    modules.push(module);
+    modules.sort();
+
+    let items = modules.iter().map(|m| format!("\n    {},", m)).collect::<String>();
+    Ok(format!("{}{}{}{}", &text[..start], open, items, &text[end..]))
+}
+
+fn write_new(path: &Path, text: &str) -> Result {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut f| f.write_all(text.as_bytes()))
+        .map_err(|e| Error::io(path, e))
+}
+
+/// Scaffolds `day` of `year` in the crate at `root`: creates its module from `template` in
+/// `src/yearYYYY`, registers it there (and the year itself in `src/lib.rs` for a new year), adds
+/// an empty input to `data` and an empty example to `data_test/YYYY`. The answers table of the
+/// example skips both parts, so the examples test ignores it until it is filled in.
+/// Nothing is written if the day already exists.
+///
+/// Returns the created and changed files.
+pub fn new_day(root: &Path, data: &DataDir, year: u16, day: u8, template: Template) -> Result<Vec<PathBuf>> {
+    let name = format!("day{:02}", day);
+    let year_name = format!("year{}", year);
+    let year_dir = root.join("src").join(&year_name);
+    let module = year_dir.join(format!("{}.rs", name));
+    let mod_path = year_dir.join("mod.rs");
+    let lib_path = root.join("src").join("lib.rs");
+
+    if module.exists() {
+        let msg = format!("day {} of {} already exists", day, year);
+        return Err(Error::io(&module, io::Error::new(io::ErrorKind::AlreadyExists, msg)));
+    }
+    let (year_mod, lib) = match fs::read_to_string(&mod_path) {
+        Ok(text) => (register(&mod_path, &text, "days!", &name)?, None),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let text = format!("pub const YEAR: u16 = {};\n\ndays! {{\n}}\n", year);
+            let lib = fs::read_to_string(&lib_path).map_err(|e| Error::io(&lib_path, e))?;
+            (register(&mod_path, &text, "days!", &name)?, Some(register(&lib_path, &lib, "years!", &year_name)?))
+        }
+        Err(e) => return Err(Error::io(&mod_path, e)),
+    };
+
+    let mut changed = Vec::new();
+    fs::create_dir_all(&year_dir).map_err(|e| Error::io(&year_dir, e))?;
+    write_new(&module, &template.source(day))?;
+    changed.push(module);
+    fs::write(&mod_path, year_mod).map_err(|e| Error::io(&mod_path, e))?;
+    changed.push(mod_path);
+    if let Some(lib) = lib {
+        fs::write(&lib_path, lib).map_err(|e| Error::io(&lib_path, e))?;
+        changed.push(lib_path);
+    }
+
+    let input = data.root.join(data.file_name(day));
+    if !input.exists() {
+        fs::create_dir_all(&data.root).and_then(|_| fs::write(&input, "")).map_err(|e| Error::io(&input, e))?;
+        changed.push(input);
+    }
+
+    let tests = root.join("data_test").join(year.to_string());
+    let example = tests.join(format!("{}_t1.txt", name));
+    if !example.exists() {
+        fs::create_dir_all(&tests).and_then(|_| fs::write(&example, "")).map_err(|e| Error::io(&example, e))?;
+        changed.push(example);
+
+        // appended rather than rewritten through `Manifest` to keep the comments
+        let answers = tests.join(MANIFEST);
+        OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&answers)
+            .and_then(|mut f| write!(f, "\n[{}_t1]\n# TODO: fill in the example and its answers\nskip = \"part1, part2\"\n", name))
+            .map_err(|e| Error::io(&answers, e))?;
+        changed.push(answers);
+    }
+
+    Ok(changed)
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::utils::{Error, Result};
+
+/// Answer to a single puzzle part
+///
+/// Integers that fit into `i64` are always stored as [`Answer::Int`], so answers of
+/// different integer types compare equal when their values are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Str(String),
+}
+
+impl From<i128> for Answer {
+    fn from(v: i128) -> Self {
+        match i64::try_from(v) {
+            Ok(v) => Self::Int(v),
+            Err(_) => Self::BigInt(v),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(v: $t) -> Self {
+                Self::from(v as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Str(s.to_string())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s.parse::<i128>() {
+            Ok(v) => Self::from(v),
+            Err(_) => Self::from(s),
+        })
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(v) => write!(f, "{v}"),
+            Self::BigInt(v) => write!(f, "{v}"),
+            Self::Str(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Day specific settings given on the command line as `--param key=value`, a bare `key` is a flag
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    items: Vec<(String, String)>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `key=value` or `key` item, later items override earlier ones
+    pub fn insert(&mut self, item: &str) {
+        let (key, value) = item.split_once('=').unwrap_or((item, ""));
+        self.items.push((key.to_string(), value.to_string()));
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.items.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    pub fn flag(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+}
+
+/// A single puzzle day: the input is parsed once and both parts are answered from it.
+pub trait Solver: Sized {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Number of parts the puzzle has (the last day of the event has only one)
+    const PARTS: u8 = 2;
+
+    fn parse(data: &str) -> Result<Self>;
+
+    /// Parses the input taking command line [`Params`] into account, most days have none
+    fn parse_with(data: &str, _params: &Params) -> Result<Self> {
+        Self::parse(data)
+    }
+
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer> {
+        Err(Error::unsolvable(format!("day {} has no part 2", Self::DAY)))
+    }
+
+    /// Extra lines about the answer to `part` asked for by [`Params`] flags,
+    /// gathered after the part is timed
+    fn details(&self, _part: u8) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Object-safe view of a parsed [`Solver`], so the registry can hold every day in one list
+pub trait DynSolver {
+    fn part1(&self) -> Result<Answer>;
+    fn part2(&self) -> Result<Answer>;
+    fn details(&self, part: u8) -> Vec<String>;
+}
+
+impl<S: Solver> DynSolver for S {
+    fn part1(&self) -> Result<Answer> {
+        Solver::part1(self)
+    }
+    fn part2(&self) -> Result<Answer> {
+        Solver::part2(self)
+    }
+    fn details(&self, part: u8) -> Vec<String> {
+        Solver::details(self, part)
+    }
+}
@@ -0,0 +1,278 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use super::num::gcd;
+
+/// Arbitrary precision signed integer, just enough for exact linear algebra
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    neg: bool,
+    /// Magnitude in base 2^32, least significant limb first, without leading zero limbs
+    mag: Vec<u32>,
+}
+
+fn trim(mut mag: Vec<u32>) -> Vec<u32> {
+    while mag.last() == Some(&0) {
+        mag.pop();
+    }
+    mag
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for (i, &x) in a.iter().enumerate() {
+        let s = x as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        res.push(s as u32);
+        carry = s >> 32;
+    }
+    res.push(carry as u32);
+    trim(res)
+}
+
+/// `a - b` for `a >= b`
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &x) in a.iter().enumerate() {
+        let mut d = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = (d < 0) as i64;
+        if d < 0 {
+            d += 1 << 32;
+        }
+        res.push(d as u32);
+    }
+    trim(res)
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u64 * y as u64 + res[i + j] as u64 + carry;
+            res[i + j] = t as u32;
+            carry = t >> 32;
+        }
+        res[i + b.len()] = carry as u32;
+    }
+    trim(res)
+}
+
+fn to_u128(mag: &[u32]) -> u128 {
+    mag.iter().rev().fold(0, |m, &l| (m << 32) | l as u128)
+}
+
+fn from_u128(m: u128) -> Vec<u32> {
+    trim(vec![m as u32, (m >> 32) as u32, (m >> 64) as u32, (m >> 96) as u32])
+}
+
+/// Quotient and remainder of magnitudes, `b` must not be zero
+fn divrem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_mag(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if a.len() <= 4 {
+        let (a, b) = (to_u128(a), to_u128(b));
+        return (from_u128(a / b), from_u128(a % b));
+    }
+    if let [d] = b {
+        let mut q = vec![0u32; a.len()];
+        let mut r = 0u64;
+        for i in (0..a.len()).rev() {
+            let cur = (r << 32) | a[i] as u64;
+            q[i] = (cur / *d as u64) as u32;
+            r = cur % *d as u64;
+        }
+        return (trim(q), trim(vec![r as u32]));
+    }
+
+    // shift and subtract one bit at a time, the numbers here are only a few limbs long
+    let mut q = vec![0u32; a.len()];
+    let mut r = Vec::<u32>::new();
+    for bit in (0..a.len() * 32).rev() {
+        let mut carry = (a[bit / 32] >> (bit % 32)) & 1;
+        for limb in r.iter_mut() {
+            let top = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = top;
+        }
+        if carry != 0 {
+            r.push(carry);
+        }
+        if cmp_mag(&r, b) != Ordering::Less {
+            r = sub_mag(&r, b);
+            q[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (trim(q), r)
+}
+
+impl BigInt {
+    fn from_parts(neg: bool, mag: Vec<u32>) -> Self {
+        let mag = trim(mag);
+        Self{neg: neg && !mag.is_empty(), mag}
+    }
+
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.neg
+    }
+
+    pub fn abs(&self) -> Self {
+        Self{neg: false, mag: self.mag.clone()}
+    }
+
+    pub fn gcd(&self, b: &Self) -> Self {
+        if self.mag.len() <= 4 && b.mag.len() <= 4 {
+            return Self::from_parts(false, from_u128(gcd(to_u128(&self.mag), to_u128(&b.mag))));
+        }
+        let (mut a, mut b) = (self.abs(), b.abs());
+        while !b.is_zero() {
+            let r = a % b.clone();
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Quotient truncated towards zero and the remainder with the sign of `self`
+    pub fn div_rem(&self, d: &Self) -> (Self, Self) {
+        assert!(!d.is_zero(), "division by zero");
+        let (q, r) = divrem_mag(&self.mag, &d.mag);
+        (Self::from_parts(self.neg != d.neg, q), Self::from_parts(self.neg, r))
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(v: i128) -> Self {
+        Self::from_parts(v < 0, from_u128(v.unsigned_abs()))
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(v: i64) -> Self {
+        Self::from(v as i128)
+    }
+}
+
+impl TryFrom<&BigInt> for i128 {
+    type Error = ();
+
+    fn try_from(v: &BigInt) -> Result<Self, ()> {
+        if v.mag.len() > 4 {
+            return Err(());
+        }
+        let m = to_u128(&v.mag);
+        if v.neg {
+            0i128.checked_sub_unsigned(m).ok_or(())
+        } else {
+            i128::try_from(m).map_err(|_| ())
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, o: &Self) -> Ordering {
+        match (self.neg, o.neg) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &o.mag),
+            (true, true) => cmp_mag(&o.mag, &self.mag),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, o: &Self) -> Option<Ordering> {
+        Some(self.cmp(o))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_parts(!self.neg, self.mag)
+    }
+}
+
+impl Add for BigInt {
+    type Output = Self;
+
+    fn add(self, o: Self) -> Self {
+        if self.neg == o.neg {
+            return Self::from_parts(self.neg, add_mag(&self.mag, &o.mag));
+        }
+        match cmp_mag(&self.mag, &o.mag) {
+            Ordering::Less => Self::from_parts(o.neg, sub_mag(&o.mag, &self.mag)),
+            _ => Self::from_parts(self.neg, sub_mag(&self.mag, &o.mag)),
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = Self;
+
+    fn sub(self, o: Self) -> Self {
+        self + -o
+    }
+}
+
+impl Mul for BigInt {
+    type Output = Self;
+
+    fn mul(self, o: Self) -> Self {
+        Self::from_parts(self.neg != o.neg, mul_mag(&self.mag, &o.mag))
+    }
+}
+
+impl Div for BigInt {
+    type Output = Self;
+
+    fn div(self, o: Self) -> Self {
+        self.div_rem(&o).0
+    }
+}
+
+impl Rem for BigInt {
+    type Output = Self;
+
+    fn rem(self, o: Self) -> Self {
+        self.div_rem(&o).1
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        // groups of 9 decimal digits, least significant first
+        let mut groups = Vec::new();
+        let mut mag = self.mag.clone();
+        while !mag.is_empty() {
+            let (q, r) = divrem_mag(&mag, &[1_000_000_000]);
+            groups.push(r.first().copied().unwrap_or(0));
+            mag = q;
+        }
+        if self.neg {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", groups.pop().unwrap())?;
+        groups.iter().rev().try_for_each(|g| write!(f, "{:09}", g))
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Shape of the sequence `x0, f(x0), f(f(x0)), ...` of a deterministic step function:
+/// the first `start` states never come back, the following ones repeat every `period` steps
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// Earliest step with the same state as step `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start { n } else { self.start + (n - self.start) % self.period }
+    }
+}
+
+/// Runs the in-place step function `f` on `x` for at most `n` steps, remembering just the `key` of
+/// every state. A repeated key is confirmed by running a copy of the state for one period and
+/// comparing it with the original, so colliding keys cost extra steps but never a wrong cycle.
+/// Returns the step `x` is left at and the cycle if it was found by then.
+fn run<S: Clone + PartialEq, K: Eq + Hash>(
+    x: &mut S, mut f: impl FnMut(&mut S), mut key: impl FnMut(&S) -> K, n: usize
+) -> (usize, Option<Cycle>) {
+    let mut seen = HashMap::<K, Vec<usize>>::new();
+    for i in 0..n {
+        let steps = seen.entry(key(x)).or_default();
+        // the nearest earlier step first, others only matter when keys collide
+        for &start in steps.iter().rev().filter(|&&s| n - i >= i - s) {
+            let period = i - start;
+            let mut y = x.clone();
+            (0..period).for_each(|_| f(&mut y));
+            if y == *x {
+                return (i, Some(Cycle{start, period}));
+            }
+        }
+        steps.push(i);
+        f(x);
+    }
+    (n, None)
+}
+
+/// Cycle of the states starting with `x0` found in a single pass over their keys. It is exact for
+/// keys telling the states apart, such as hashes; with colliding keys `period` is still a period of
+/// the states but `start` may be off. Never returns for a sequence without a cycle.
+pub fn detect<S: Clone + PartialEq, K: Eq + Hash>(x0: &S, f: impl FnMut(&mut S), key: impl FnMut(&S) -> K) -> Cycle {
+    run(&mut x0.clone(), f, key, usize::MAX).1.expect("no cycle within usize::MAX steps")
+}
+
+/// State after `n` steps, jumping over the whole periods of the cycle [`detect`]ed on the way
+pub fn nth<S: Clone + PartialEq, K: Eq + Hash>(mut x: S, mut f: impl FnMut(&mut S), key: impl FnMut(&S) -> K, n: usize) -> S {
+    if let (i, Some(cycle)) = run(&mut x, &mut f, key, n) {
+        (0..(n - i) % cycle.period).for_each(|_| f(&mut x));
+    }
+    x
+}
+
+/// 64-bit hash of a state, a cheap key for [`detect`] and [`nth`]
+pub fn hash<S: Hash>(x: &S) -> u64 {
+    let mut h = DefaultHasher::new();
+    x.hash(&mut h);
+    h.finish()
+}
+
+/// [`nth`] keyed by the [`hash`] of every state
+pub fn nth_by_hash<S: Clone + Eq + Hash>(x: S, f: impl FnMut(&mut S), n: usize) -> S {
+    nth(x, f, hash, n)
+}
@@ -0,0 +1,62 @@
+use super::Point;
+
+/// Direction on a grid where `y` grows downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Every direction clockwise starting from `Up`
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// Parses `U/R/D/L`, `^/>/v/<` and compass points `N/E/S/W`
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' | 'N' => Some(Self::Up),
+            'R' | '>' | 'E' => Some(Self::Right),
+            'D' | 'v' | 'S' => Some(Self::Down),
+            'L' | '<' | 'W' => Some(Self::Left),
+            _ => None,
+        }
+    }
+
+    /// Position in [`Dir::ALL`]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    /// Unit step in this direction
+    pub fn delta<T: From<i8>>(self) -> Point<T> {
+        let (x, y) = match self {
+            Self::Up => (0, -1),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+        };
+        Point{ x: T::from(x), y: T::from(y) }
+    }
+}
@@ -0,0 +1,216 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::{Error, Line, Point, Result};
+
+/// Integer types usable as grid coordinates
+pub trait Coord: Copy + 'static {
+    fn to_i64(self) -> i64;
+    fn from_i64(v: i64) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            fn to_i64(self) -> i64 { self as i64 }
+            fn from_i64(v: i64) -> Self { v as Self }
+        })*
+    };
+}
+
+coord!(i16, i32, i64, isize, u16, u32, usize);
+
+/// Up, right, down, left
+const NEIGHBOURS4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Clockwise starting from the top left corner
+const NEIGHBOURS8: [(i64, i64); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+/// Rectangular grid stored row by row, cells are addressed by `Point{x: column, y: row}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self where T: Clone {
+        Self{ width, height, cells: vec![value; width * height] }
+    }
+
+    /// Grid with the cells produced by `f(x, y)`
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+        Self{ width, height, cells }
+    }
+
+    /// `None` if the rows are of different lengths
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+        let height = if width == 0 { 0 } else { rows.len() };
+        Some(Self{ width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset<C: Coord>(&self, p: Point<C>) -> Option<usize> {
+        let (x, y) = (p.x.to_i64(), p.y.to_i64());
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+
+    fn point<C: Coord>(&self, offset: usize) -> Point<C> {
+        Point{ x: C::from_i64((offset % self.width) as i64), y: C::from_i64((offset / self.width) as i64) }
+    }
+
+    pub fn contains<C: Coord>(&self, p: Point<C>) -> bool {
+        self.offset(p).is_some()
+    }
+
+    pub fn get<C: Coord>(&self, p: Point<C>) -> Option<&T> {
+        self.offset(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<C: Coord>(&mut self, p: Point<C>) -> Option<&mut T> {
+        self.offset(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Cell at `p` of the plane tiled with copies of the grid, `None` for an empty grid
+    pub fn wrapped<C: Coord>(&self, p: Point<C>) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let x = p.x.to_i64().rem_euclid(self.width as i64);
+        let y = p.y.to_i64().rem_euclid(self.height as i64);
+        Some(&self.cells[y as usize * self.width + x as usize])
+    }
+
+    fn shifted<C: Coord>(&self, p: Point<C>, deltas: &'static [(i64, i64)]) -> impl Iterator<Item = Point<C>> + '_ {
+        deltas
+            .iter()
+            .map(move |(dx, dy)| Point{ x: p.x.to_i64() + dx, y: p.y.to_i64() + dy })
+            .filter(|q| self.contains(*q))
+            .map(|q| Point{ x: C::from_i64(q.x), y: C::from_i64(q.y) })
+    }
+
+    /// Orthogonal neighbours of `p` inside the grid: up, right, down and left
+    pub fn neighbours4<C: Coord>(&self, p: Point<C>) -> impl Iterator<Item = Point<C>> + '_ {
+        self.shifted(p, &NEIGHBOURS4)
+    }
+
+    /// Orthogonal and diagonal neighbours of `p` inside the grid
+    pub fn neighbours8<C: Coord>(&self, p: Point<C>) -> impl Iterator<Item = Point<C>> + '_ {
+        self.shifted(p, &NEIGHBOURS8)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter<C: Coord>(&self) -> impl Iterator<Item = (Point<C>, &T)> + '_ {
+        self.cells.iter().enumerate().map(|(i, v)| (self.point(i), v))
+    }
+
+    /// Positions of the cells matching `pred`, row by row
+    pub fn positions<'a, C: Coord>(&'a self, pred: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = Point<C>> + 'a {
+        self.cells.iter().enumerate().filter(move |(_, v)| pred(v)).map(|(i, _)| self.point(i))
+    }
+
+    /// Position of the first cell equal to `value`
+    pub fn find<C: Coord>(&self, value: &T) -> Option<Point<C>> where T: PartialEq {
+        self.cells.iter().position(|v| v == value).map(|i| self.point(i))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid{ width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Grid mirrored along its main diagonal
+    pub fn transposed(&self) -> Self where T: Clone {
+        Self::from_fn(self.height, self.width, |x, y| self.cells[x * self.width + y].clone())
+    }
+
+    /// Grid rotated by 90 degrees clockwise
+    pub fn rotated_cw(&self) -> Self where T: Clone {
+        Self::from_fn(self.height, self.width, |x, y| self.cells[(self.height - 1 - x) * self.width + y].clone())
+    }
+
+    /// Grid rotated by 90 degrees counterclockwise
+    pub fn rotated_ccw(&self) -> Self where T: Clone {
+        Self::from_fn(self.height, self.width, |x, y| self.cells[x * self.width + self.width - 1 - y].clone())
+    }
+}
+
+impl Grid<char> {
+    /// Parses a non-empty rectangular grid where each character must be in `allowed`
+    pub fn parse<'a>(lines: impl IntoIterator<Item = Line<'a>>, allowed: &str) -> Result<Self> {
+        let mut grid = Self{ width: 0, height: 0, cells: Vec::new() };
+        for line in lines {
+            if let Some((i, c)) = line.text.chars().enumerate().find(|(_, c)| !allowed.contains(*c)) {
+                return Err(Error::parse(line.no, i + 1, format!("unexpected `{}`", c)));
+            }
+            let len = line.text.chars().count();
+            if len == 0 || (grid.height > 0 && len != grid.width) {
+                return Err(line.error(line.text, "grid rows must be non-empty and of equal length"));
+            }
+            grid.cells.extend(line.text.chars());
+            grid.width = len;
+            grid.height += 1;
+        }
+        if grid.height == 0 {
+            return Err(Error::parse(1, 1, "empty grid"));
+        }
+        Ok(grid)
+    }
+}
+
+impl<T, C: Coord> Index<Point<C>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<C>) -> &T {
+        let (x, y) = (p.x.to_i64(), p.y.to_i64());
+        self.get(p).unwrap_or_else(|| panic!("({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height))
+    }
+}
+
+impl<T, C: Coord> IndexMut<Point<C>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<C>) -> &mut T {
+        let (x, y, w, h) = (p.x.to_i64(), p.y.to_i64(), self.width, self.height);
+        self.get_mut(p).unwrap_or_else(|| panic!("({}, {}) is outside of the {}x{} grid", x, y, w, h))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+use super::num::Int;
+
+/// Half-open range `start..end`, empty when `start >= end`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Int> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self{start, end}
+    }
+
+    /// Range of `len` values beginning at `start`
+    pub fn with_len(start: T, len: T) -> Self {
+        Self{start, end: start + len}
+    }
+
+    /// Closed range `first..=last`
+    pub fn inclusive(first: T, last: T) -> Self {
+        Self{start: first, end: last + T::ONE}
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() { T::ZERO } else { self.end - self.start }
+    }
+
+    pub fn contains(&self, v: T) -> bool {
+        self.start <= v && v < self.end
+    }
+
+    pub fn intersection(&self, i: &Self) -> Self {
+        Self{start: self.start.max(i.start), end: self.end.min(i.end)}
+    }
+
+    /// Parts below `at` and from `at` on, either may be empty
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Self{start: self.start, end: at}, Self{start: at, end: self.end})
+    }
+}
+
+/// Union of disjoint intervals, kept sorted with touching intervals merged
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Int> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self{intervals: Vec::new()}
+    }
+
+    pub fn insert(&mut self, i: Interval<T>) {
+        if i.is_empty() {
+            return;
+        }
+        // everything ending before `i` stays in front, everything starting after it behind
+        let lo = self.intervals.partition_point(|j| j.end < i.start);
+        let hi = self.intervals.partition_point(|j| j.start <= i.end);
+        let merged = self.intervals[lo..hi].iter().fold(i, |m, j| {
+            Interval{start: m.start.min(j.start), end: m.end.max(j.end)}
+        });
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |s, i| s + i.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn contains(&self, v: T) -> bool {
+        let k = self.intervals.partition_point(|i| i.end <= v);
+        self.intervals.get(k).is_some_and(|i| i.contains(v))
+    }
+
+    pub fn union(&self, s: &Self) -> Self {
+        let mut res = self.clone();
+        s.intervals.iter().for_each(|&i| res.insert(i));
+        res
+    }
+
+    pub fn intersection(&self, s: &Self) -> Self {
+        let (mut a, mut b) = (0, 0);
+        let mut res = Self::new();
+        while a < self.intervals.len() && b < s.intervals.len() {
+            let (i, j) = (self.intervals[a], s.intervals[b]);
+            let common = i.intersection(&j);
+            if !common.is_empty() {
+                res.intervals.push(common);
+            }
+            if i.end < j.end { a += 1 } else { b += 1 }
+        }
+        res
+    }
+
+    pub fn difference(&self, s: &Self) -> Self {
+        let mut res = Self::new();
+        let mut b = 0;
+        for &i in self.intervals.iter() {
+            let mut rest = i;
+            while b < s.intervals.len() && s.intervals[b].end <= rest.start {
+                b += 1;
+            }
+            let mut k = b;
+            while k < s.intervals.len() && s.intervals[k].start < rest.end {
+                let (before, after) = rest.split_at(s.intervals[k].start);
+                if !before.is_empty() {
+                    res.intervals.push(before);
+                }
+                rest = after.split_at(s.intervals[k].end).1;
+                k += 1;
+            }
+            if !rest.is_empty() {
+                res.intervals.push(rest);
+            }
+        }
+        res
+    }
+
+    /// Values below `at` and from `at` on
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Self::new();
+        let mut above = Self::new();
+        for i in self.intervals.iter() {
+            let (b, a) = i.split_at(at);
+            if !b.is_empty() { below.intervals.push(b); }
+            if !a.is_empty() { above.intervals.push(a); }
+        }
+        (below, above)
+    }
+}
+
+impl<T: Int> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut res = Self::new();
+        iter.into_iter().for_each(|i| res.insert(i));
+        res
+    }
+}
+
+impl<T: Int> From<Interval<T>> for IntervalSet<T> {
+    fn from(i: Interval<T>) -> Self {
+        Self::from_iter([i])
+    }
+}
+
+/// Piecewise shift of values, ranges without a rule map onto themselves
+#[derive(Debug, Clone, Default)]
+pub struct RangeMap<T> {
+    rules: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Int> RangeMap<T> {
+    pub fn new() -> Self {
+        Self{rules: Vec::new()}
+    }
+
+    /// Maps `from` onto the range of the same length beginning at `to`
+    pub fn insert(&mut self, from: Interval<T>, to: T) {
+        self.rules.push((from, to));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn get(&self, v: T) -> T {
+        self.rules
+            .iter()
+            .find(|(from, _)| from.contains(v))
+            .map_or(v, |&(from, to)| v - from.start + to)
+    }
+
+    /// Image of every value in `s`
+    pub fn apply(&self, s: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut res = IntervalSet::new();
+        let mut rest = s.clone();
+        for &(src, to) in self.rules.iter() {
+            let from = IntervalSet::from(src);
+            for i in rest.intersection(&from).intervals() {
+                res.insert(Interval::with_len(i.start - src.start + to, i.len()));
+            }
+            rest = rest.difference(&from);
+        }
+        res.union(&rest)
+    }
+}
+
+/// Axis-aligned box made of one interval per dimension
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct IntervalBox<T, const N: usize> {
+    pub dims: [Interval<T>; N],
+}
+
+impl<T: Int, const N: usize> IntervalBox<T, N> {
+    pub fn new(dims: [Interval<T>; N]) -> Self {
+        Self{dims}
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dims.iter().any(|i| i.is_empty())
+    }
+
+    /// Number of points inside
+    pub fn volume(&self) -> T {
+        self.dims.iter().fold(T::ONE, |v, i| v * i.len())
+    }
+
+    /// Parts with coordinate `dim` below `at` and from `at` on, `None` instead of an empty box
+    pub fn split_at(&self, dim: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let (lo, hi) = self.dims[dim].split_at(at);
+        let part = |i: Interval<T>| {
+            let mut b = *self;
+            b.dims[dim] = i;
+            (!b.is_empty()).then_some(b)
+        };
+        (part(lo), part(hi))
+    }
+}
@@ -0,0 +1,38 @@
+use super::{BigInt, Rational};
+
+/// Exact solution of the square system `a·x = b` by fraction-free (Bareiss) elimination,
+/// `None` when `a` is singular
+pub fn solve(a: &[Vec<BigInt>], b: &[BigInt]) -> Option<Vec<Rational>> {
+    let n = b.len();
+    assert!(a.len() == n && a.iter().all(|row| row.len() == n), "the matrix must be square");
+
+    let mut m = a
+        .iter()
+        .zip(b)
+        .map(|(row, v)| row.iter().chain([v]).cloned().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    // every entry stays an integer, divisions by the previous pivot are exact
+    let mut prev = BigInt::from(1i128);
+    for k in 0..n {
+        let p = (k..n).find(|&i| !m[i][k].is_zero())?;
+        m.swap(k, p);
+        for i in k + 1..n {
+            for j in k + 1..=n {
+                let v = m[i][j].clone() * m[k][k].clone() - m[i][k].clone() * m[k][j].clone();
+                m[i][j] = v / prev.clone();
+            }
+            m[i][k] = BigInt::zero();
+        }
+        prev = m[k][k].clone();
+    }
+
+    let mut x = vec![Rational::from(0i128); n];
+    for i in (0..n).rev() {
+        let s = (i + 1..n).fold(Rational::from(m[i][n].clone()), |s, j| {
+            s - Rational::from(m[i][j].clone()) * x[j].clone()
+        });
+        x[i] = s / Rational::from(m[i][i].clone());
+    }
+    Some(x)
+}
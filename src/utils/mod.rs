@@ -0,0 +1,150 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use super::solver::Answer;
+
+mod bigint;
+pub mod cycle;
+mod dir;
+mod grid;
+mod interval;
+pub mod linalg;
+pub mod num;
+mod point;
+mod point3;
+mod polygon;
+mod rational;
+pub mod search;
+
+pub use bigint::BigInt;
+pub use dir::Dir;
+pub use grid::{Coord, Grid};
+pub use interval::{Interval, IntervalBox, IntervalSet, RangeMap};
+pub use point::Point;
+pub use point3::{Cuboid, Point3};
+pub use polygon::Polygon;
+pub use rational::Rational;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Input or manifest file can not be read or written
+    Io { path: PathBuf, source: io::Error },
+    /// Malformed input, `line` and `col` are 1-based
+    Parse { line: usize, col: usize, msg: String },
+    /// Well-formed input the solver has no answer for
+    Unsolvable(String),
+    /// Malformed `--param` value
+    Param { key: String, msg: String },
+    WrongAnswer { day: u8, part: u8, expected: Answer, actual: Answer },
+}
+
+pub type Result<T = ()> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        Self::Io{ path: path.into(), source }
+    }
+
+    pub fn parse(line: usize, col: usize, msg: impl Into<String>) -> Self {
+        Self::Parse{ line, col, msg: msg.into() }
+    }
+
+    pub fn unsolvable(msg: impl Into<String>) -> Self {
+        Self::Unsolvable(msg.into())
+    }
+
+    pub fn param(key: &str, msg: impl Into<String>) -> Self {
+        Self::Param{ key: key.to_string(), msg: msg.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io{ path, source } => write!(f, "{}: {}", path.display(), source),
+            Self::Parse{ line, col, msg } => write!(f, "parse error at line {}, column {}: {}", line, col, msg),
+            Self::Unsolvable(msg) => write!(f, "unsolvable input: {}", msg),
+            Self::Param{ key, msg } => write!(f, "invalid param `{}`: {}", key, msg),
+            Self::WrongAnswer{ day, part, expected, actual } => {
+                write!(f, "wrong answer at day {} part {}: got {}, expected {}", day, part, actual, expected)
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io{ source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A line of puzzle input together with its 1-based number, used to locate parse errors
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub no: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based column of `part`, which is expected to be a slice of this line
+    pub fn col(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let at = part.as_ptr() as usize;
+        if at >= start && at <= start + self.text.len() {
+            self.text[..at - start].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// Parse error pointing at `part` of this line
+    pub fn error(&self, part: &str, msg: impl Into<String>) -> Error {
+        Error::parse(self.no, self.col(part), msg)
+    }
+
+    /// Parses `token`, a slice of this line, reporting its position on failure
+    pub fn parse<T: FromStr>(&self, token: &'a str) -> Result<T> {
+        token.trim().parse().map_err(|_| self.error(token, format!("unexpected `{}`", token)))
+    }
+
+    /// Splits the line at the first `sep`, which must be present
+    pub fn split_once(&self, sep: &str) -> Result<(&'a str, &'a str)> {
+        self.text
+            .split_once(sep)
+            .ok_or_else(|| self.error(&self.text[self.text.len()..], format!("expected `{}`", sep)))
+    }
+
+    /// Character at the 0-based position `i`
+    pub fn char_at(&self, i: usize) -> Result<char> {
+        self.text
+            .chars()
+            .nth(i)
+            .ok_or_else(|| self.error(&self.text[self.text.len()..], "line is too short"))
+    }
+}
+
+/// Lines of the input, numbered from 1
+pub fn lines(data: &str) -> impl Iterator<Item = Line<'_>> + Clone {
+    data.split('\n').enumerate().map(|(i, text)| Line{ no: i + 1, text })
+}
+
+/// Blocks of non-empty lines separated by empty ones
+pub fn sections(data: &str) -> Vec<Vec<Line<'_>>> {
+    let mut res = vec![Vec::new()];
+    for line in lines(data) {
+        if line.text.is_empty() {
+            if !res.last().unwrap().is_empty() {
+                res.push(Vec::new());
+            }
+        } else {
+            res.last_mut().unwrap().push(line);
+        }
+    }
+    res.retain(|s| !s.is_empty());
+    res
+}
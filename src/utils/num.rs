@@ -0,0 +1,147 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Primitive integer types the number theory helpers work with
+pub trait Int: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, v: Self) -> Option<Self>;
+    fn checked_mul(self, v: Self) -> Option<Self>;
+    /// Absolute value, the identity for unsigned types
+    fn abs(self) -> Self;
+}
+
+/// Signed integers, needed wherever Bézout coefficients show up
+pub trait Signed: Int + Neg<Output = Self> {}
+
+macro_rules! int {
+    ($abs:expr; $($t:ty),*) => {
+        $(impl Int for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, v: Self) -> Option<Self> { <$t>::checked_add(self, v) }
+            fn checked_mul(self, v: Self) -> Option<Self> { <$t>::checked_mul(self, v) }
+            fn abs(self) -> Self { $abs(self) }
+        })*
+    };
+}
+
+int!(|v| v; u8, u16, u32, u64, u128, usize);
+int!(<Self>::abs; i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// Greatest common divisor, never negative, `gcd(0, 0) == 0`
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, never negative, `None` on overflow
+pub fn lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a.abs() / gcd(a, b)).checked_mul(b.abs())
+}
+
+/// Least common multiple of all values, `1` for none
+pub fn lcm_all<T: Int>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// `(g, x, y)` such that `a*x + b*y == g == gcd(a, b)`
+pub fn ext_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::ZERO { (-r0, -x0, -y0) } else { (r0, x0, y0) }
+}
+
+/// Non-negative remainder for a positive `m`
+pub fn modulo<T: Int>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::ZERO { r + m } else { r }
+}
+
+/// `x` in `0..m` with `a*x ≡ 1 (mod m)`, `None` when `a` and `m` are not coprime
+pub fn mod_inv<T: Signed>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = ext_gcd(modulo(a, m), m);
+    (g == T::ONE).then(|| modulo(x, m))
+}
+
+/// `a*b mod m` for `a` and `b` in `0..m`, without overflowing
+pub fn mod_mul<T: Int>(a: T, b: T, m: T) -> T {
+    if let Some(p) = a.checked_mul(b) {
+        return p % m;
+    }
+    let two = T::ONE + T::ONE;
+    let mut res = T::ZERO;
+    let (mut a, mut b) = (a, b);
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            res = mod_add(res, a, m);
+        }
+        a = mod_add(a, a, m);
+        b = b / two;
+    }
+    res
+}
+
+fn mod_add<T: Int>(a: T, b: T, m: T) -> T {
+    // a + b - m, reordered to stay in range
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+/// `base^exp mod m` for a positive `m`
+pub fn mod_pow<T: Int>(base: T, exp: T, m: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut res = T::ONE % m;
+    let (mut base, mut exp) = (modulo(base, m), exp);
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            res = mod_mul(res, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp = exp / two;
+    }
+    res
+}
+
+/// Chinese Remainder Theorem for `x ≡ r (mod m)` pairs whose moduli need not be coprime.
+///
+/// Returns `(x, lcm of the moduli)` with `x` the smallest non-negative solution,
+/// `None` if the congruences contradict each other or the modulus overflows.
+pub fn crt<T: Signed>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences.into_iter().try_fold((T::ZERO, T::ONE), |(r1, m1), (r2, m2)| {
+        let r2 = modulo(r2, m2);
+        let (g, p, _) = ext_gcd(m1, m2);
+        let diff = modulo(r2 - r1, m2);
+        if diff % g != T::ZERO {
+            return None;
+        }
+        let m = lcm(m1, m2)?;
+        let step = m2 / g;
+        // k ≡ p * diff/g (mod m2/g) moves r1 onto the second congruence
+        let k = mod_mul(modulo(p, step), diff / g, step);
+        let r = mod_add(r1, mod_mul(k, m1 % m, m), m);
+        Some((r, m))
+    })
+}
@@ -0,0 +1,140 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self{x, y}
+    }
+
+    /// Lossless conversion to a wider coordinate type
+    pub fn cast<U: From<T>>(self) -> Point<U> {
+        Point{x: U::from(self.x), y: U::from(self.y)}
+    }
+
+    /// Conversion to another coordinate type, `None` if a coordinate does not fit
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point{x: U::try_from(self.x).ok()?, y: U::try_from(self.y).ok()?})
+    }
+}
+
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point<T> {
+    pub fn manhattan(&self, q: &Self) -> T {
+        abs_diff(self.x, q.x) + abs_diff(self.y, q.y)
+    }
+
+    /// Distance where diagonal steps count as one
+    pub fn chebyshev(&self, q: &Self) -> T {
+        let (dx, dy) = (abs_diff(self.x, q.x), abs_diff(self.y, q.y));
+        if dx > dy { dx } else { dy }
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Point<T> {
+    pub fn dot(&self, q: &Self) -> T {
+        self.x * q.x + self.y * q.y
+    }
+
+    /// Z component of the cross product, positive when `q` is clockwise from `self` with `y` growing downwards
+    pub fn cross(&self, q: &Self) -> T {
+        self.x * q.y - self.y * q.x
+    }
+
+    /// Squared length
+    pub fn norm2(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Neg<Output = T>> Point<T> {
+    /// Rotated by 90 degrees clockwise around the origin, with `y` growing downwards
+    pub fn rot90_cw(self) -> Self {
+        Self{x: -self.y, y: self.x}
+    }
+
+    /// Rotated by 90 degrees counterclockwise around the origin, with `y` growing downwards
+    pub fn rot90_ccw(self) -> Self {
+        Self{x: self.y, y: -self.x}
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self{x, y}
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(p: Point<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, q: Self) -> Self {
+        Self{x: self.x + q.x, y: self.y + q.y}
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, q: Self) -> Self {
+        Self{x: self.x - q.x, y: self.y - q.y}
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self{x: self.x * k, y: self.y * k}
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Point<T> {
+    type Output = Self;
+
+    fn div(self, k: T) -> Self {
+        Self{x: self.x / k, y: self.y / k}
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self{x: -self.x, y: -self.y}
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, q: Self) {
+        self.x += q.x;
+        self.y += q.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, q: Self) {
+        self.x -= q.x;
+        self.y -= q.y;
+    }
+}
+
+impl<T: Copy + MulAssign> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, k: T) {
+        self.x *= k;
+        self.y *= k;
+    }
+}
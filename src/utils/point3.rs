@@ -0,0 +1,228 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use super::{Coord, Line, Result};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self{x, y, z}
+    }
+
+    /// Lossless conversion to a wider coordinate type
+    pub fn cast<U: From<T>>(self) -> Point3<U> {
+        Point3{x: U::from(self.x), y: U::from(self.y), z: U::from(self.z)}
+    }
+
+    /// Conversion to another coordinate type, `None` if a coordinate does not fit
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point3<U>> {
+        Some(Point3{x: U::try_from(self.x).ok()?, y: U::try_from(self.y).ok()?, z: U::try_from(self.z).ok()?})
+    }
+}
+
+impl<T: FromStr> Point3<T> {
+    /// Parses `x,y,z`, a slice of `line`, spaces around the coordinates are allowed
+    pub fn parse<'a>(line: &Line<'a>, s: &'a str) -> Result<Self> {
+        let v = s.split(',').map(|t| line.parse::<T>(t)).collect::<Result<Vec<_>>>()?;
+        let Ok([x, y, z]) = <[T; 3]>::try_from(v) else {
+            return Err(line.error(s, "expected `x,y,z`"));
+        };
+        Ok(Self{x, y, z})
+    }
+}
+
+fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> Point3<T> {
+    pub fn manhattan(&self, q: &Self) -> T {
+        abs_diff(self.x, q.x) + abs_diff(self.y, q.y) + abs_diff(self.z, q.z)
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Point3<T> {
+    pub fn dot(&self, q: &Self) -> T {
+        self.x * q.x + self.y * q.y + self.z * q.z
+    }
+
+    pub fn cross(&self, q: &Self) -> Self {
+        Self{
+            x: self.y * q.z - self.z * q.y,
+            y: self.z * q.x - self.x * q.z,
+            z: self.x * q.y - self.y * q.x,
+        }
+    }
+
+    /// Squared length
+    pub fn norm2(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self{x, y, z}
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(p: Point3<T>) -> Self {
+        (p.x, p.y, p.z)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, q: Self) -> Self {
+        Self{x: self.x + q.x, y: self.y + q.y, z: self.z + q.z}
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, q: Self) -> Self {
+        Self{x: self.x - q.x, y: self.y - q.y, z: self.z - q.z}
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self{x: self.x * k, y: self.y * k, z: self.z * k}
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Point3<T> {
+    type Output = Self;
+
+    fn div(self, k: T) -> Self {
+        Self{x: self.x / k, y: self.y / k, z: self.z / k}
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self{x: -self.x, y: -self.y, z: -self.z}
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point3<T> {
+    fn add_assign(&mut self, q: Self) {
+        self.x += q.x;
+        self.y += q.y;
+        self.z += q.z;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, q: Self) {
+        self.x -= q.x;
+        self.y -= q.y;
+        self.z -= q.z;
+    }
+}
+
+impl<T: Copy + MulAssign> MulAssign<T> for Point3<T> {
+    fn mul_assign(&mut self, k: T) {
+        self.x *= k;
+        self.y *= k;
+        self.z *= k;
+    }
+}
+
+/// Axis-aligned box, both corners are inclusive
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Cuboid<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+fn min_max<T: PartialOrd>(a: T, b: T) -> (T, T) {
+    if a > b { (b, a) } else { (a, b) }
+}
+
+impl<T: Copy + PartialOrd> Cuboid<T> {
+    /// Box spanning two opposite corners given in any order
+    pub fn new(a: Point3<T>, b: Point3<T>) -> Self {
+        let (x0, x1) = min_max(a.x, b.x);
+        let (y0, y1) = min_max(a.y, b.y);
+        let (z0, z1) = min_max(a.z, b.z);
+        Self{min: Point3{x: x0, y: y0, z: z0}, max: Point3{x: x1, y: y1, z: z1}}
+    }
+
+    pub fn contains(&self, p: Point3<T>) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x
+            && self.min.y <= p.y && p.y <= self.max.y
+            && self.min.z <= p.z && p.z <= self.max.z
+    }
+
+    pub fn intersects(&self, b: &Self) -> bool {
+        self.intersection(b).is_some()
+    }
+
+    pub fn intersection(&self, b: &Self) -> Option<Self> {
+        let max = |a: T, b: T| if a > b { a } else { b };
+        let min = |a: T, b: T| if a < b { a } else { b };
+        let res = Self{
+            min: Point3{x: max(self.min.x, b.min.x), y: max(self.min.y, b.min.y), z: max(self.min.z, b.min.z)},
+            max: Point3{x: min(self.max.x, b.max.x), y: min(self.max.y, b.max.y), z: min(self.max.z, b.max.z)},
+        };
+        (res.min.x <= res.max.x && res.min.y <= res.max.y && res.min.z <= res.max.z).then_some(res)
+    }
+
+    /// One cell thick slice at the bottom
+    pub fn bottom(&self) -> Self {
+        Self{min: self.min, max: Point3{z: self.min.z, ..self.max}}
+    }
+
+    /// One cell thick slice at the top
+    pub fn top(&self) -> Self {
+        Self{min: Point3{z: self.max.z, ..self.min}, max: self.max}
+    }
+}
+
+impl<T: Coord> Cuboid<T> {
+    /// Number of points inside
+    pub fn volume(&self) -> u64 {
+        let d = |a: T, b: T| (b.to_i64() - a.to_i64() + 1) as u64;
+        d(self.min.x, self.max.x) * d(self.min.y, self.max.y) * d(self.min.z, self.max.z)
+    }
+
+    /// Every point inside, `x` changing fastest
+    pub fn points(&self) -> impl Iterator<Item = Point3<T>> {
+        let (min, max) = (self.min, self.max);
+        (min.z.to_i64()..=max.z.to_i64()).flat_map(move |z| {
+            (min.y.to_i64()..=max.y.to_i64()).flat_map(move |y| {
+                (min.x.to_i64()..=max.x.to_i64()).map(move |x| Point3{x: T::from_i64(x), y: T::from_i64(y), z: T::from_i64(z)})
+            })
+        })
+    }
+}
+
+impl<T: Copy + Add<Output = T>> Add<Point3<T>> for Cuboid<T> {
+    type Output = Self;
+
+    fn add(self, d: Point3<T>) -> Self {
+        Self{min: self.min + d, max: self.max + d}
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> Sub<Point3<T>> for Cuboid<T> {
+    type Output = Self;
+
+    fn sub(self, d: Point3<T>) -> Self {
+        Self{min: self.min - d, max: self.max - d}
+    }
+}
@@ -0,0 +1,87 @@
+use super::num::gcd;
+use super::{Dir, Point};
+
+type Pos = Point<i64>;
+
+/// Closed polygon on the integer lattice, the last vertex connects back to the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Pos>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Pos>) -> Self {
+        Self{vertices}
+    }
+
+    /// Polygon traced from the origin by `(direction, length)` moves, ending back at the origin
+    pub fn from_moves(moves: impl IntoIterator<Item = (Dir, i64)>) -> Self {
+        let mut vertices = Vec::new();
+        let mut cur = Pos::default();
+        for (d, n) in moves {
+            vertices.push(cur);
+            cur += d.delta() * n;
+        }
+        Self{vertices}
+    }
+
+    pub fn vertices(&self) -> &[Pos] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Pos, Pos)> + '_ {
+        self.vertices.iter().zip(self.vertices.iter().cycle().skip(1)).map(|(&a, &b)| (a, b))
+    }
+
+    /// Twice the signed area by the shoelace formula, positive for clockwise vertices with `y` growing downwards
+    pub fn double_area(&self) -> i64 {
+        self.edges().map(|(a, b)| a.cross(&b)).sum()
+    }
+
+    /// Lattice points on the edges
+    pub fn boundary(&self) -> i64 {
+        self.edges().map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs())).sum()
+    }
+
+    /// Lattice points strictly inside, by Pick's theorem
+    pub fn interior(&self) -> i64 {
+        if self.vertices.is_empty() {
+            return 0;
+        }
+        (self.double_area().abs() - self.boundary() + 2) / 2
+    }
+
+    /// Lattice points inside or on the edges
+    pub fn lattice_points(&self) -> i64 {
+        self.interior() + self.boundary()
+    }
+
+    pub fn on_boundary(&self, p: Pos) -> bool {
+        self.edges().any(|(a, b)| {
+            (b - a).cross(&(p - a)) == 0 && (p - a).dot(&(p - b)) <= 0
+        })
+    }
+
+    /// How many times the polygon winds around `p`, counterclockwise with `y` growing downwards counts as positive
+    ///
+    /// Only meaningful for points off the boundary.
+    pub fn winding_number(&self, p: Pos) -> i64 {
+        self.edges()
+            .map(|(a, b)| {
+                let side = (b - a).cross(&(p - a));
+                if a.y <= p.y && b.y > p.y && side > 0 {
+                    -1
+                } else if a.y > p.y && b.y <= p.y && side < 0 {
+                    1
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    /// Whether `p` lies strictly inside
+    pub fn contains(&self, p: Pos) -> bool {
+        !self.on_boundary(p) && self.winding_number(p) != 0
+    }
+}
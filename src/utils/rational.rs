@@ -0,0 +1,116 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::BigInt;
+
+/// Exact fraction kept in lowest terms with a positive denominator
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: BigInt,
+    den: BigInt,
+}
+
+impl Rational {
+    /// `num / den`, `den` must not be zero
+    pub fn new(num: BigInt, den: BigInt) -> Self {
+        assert!(!den.is_zero(), "zero denominator");
+        let (num, den) = if den.is_negative() { (-num, -den) } else { (num, den) };
+        let g = num.gcd(&den);
+        Self{num: num / g.clone(), den: den / g}
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.num
+    }
+
+    pub fn denom(&self) -> &BigInt {
+        &self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == BigInt::from(1i128)
+    }
+
+    /// The value if it is a whole number fitting into `i128`
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.is_integer() { i128::try_from(&self.num).ok() } else { None }
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(v: BigInt) -> Self {
+        Self{num: v, den: BigInt::from(1i128)}
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(v: i128) -> Self {
+        Self::from(BigInt::from(v))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self{num: -self.num, den: self.den}
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, o: Self) -> Self {
+        Self::new(self.num * o.den.clone() + o.num * self.den.clone(), self.den * o.den)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, o: Self) -> Self {
+        self + -o
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, o: Self) -> Self {
+        Self::new(self.num * o.num, self.den * o.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, o: Self) -> Self {
+        Self::new(self.num * o.den, self.den * o.num)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, o: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(o))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, o: &Self) -> std::cmp::Ordering {
+        (self.num.clone() * o.den.clone()).cmp(&(o.num.clone() * self.den.clone()))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use super::num::Int;
+
+/// Cheapest way to a goal, `path` runs from a start to the goal inclusive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+}
+
+/// States in the order breadth-first search reaches them, with their distance from the nearest start.
+///
+/// States sharing a `key` are visited once, successors are only generated for states actually yielded,
+/// so the search can be cut off with `take_while`.
+pub struct Bfs<S, K, FK, FS> {
+    queue: VecDeque<(S, usize)>,
+    seen: HashSet<K>,
+    key: FK,
+    successors: FS,
+}
+
+impl<S, K, FK, FS, I> Bfs<S, K, FK, FS>
+where
+    K: Eq + Hash,
+    FK: FnMut(&S) -> K,
+    FS: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    pub fn new(starts: impl IntoIterator<Item = S>, mut key: FK, successors: FS) -> Self {
+        let mut seen = HashSet::new();
+        let queue = starts
+            .into_iter()
+            .filter(|s| seen.insert(key(s)))
+            .map(|s| (s, 0))
+            .collect();
+        Self{queue, seen, key, successors}
+    }
+}
+
+impl<S, K, FK, FS, I> Iterator for Bfs<S, K, FK, FS>
+where
+    K: Eq + Hash,
+    FK: FnMut(&S) -> K,
+    FS: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    type Item = (S, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (s, d) = self.queue.pop_front()?;
+        for n in (self.successors)(&s) {
+            if self.seen.insert((self.key)(&n)) {
+                self.queue.push_back((n, d + 1));
+            }
+        }
+        Some((s, d))
+    }
+}
+
+/// States reached so far, each with the index of the state it was reached from
+struct Tree<S> {
+    nodes: Vec<(S, usize)>,
+}
+
+impl<S: Clone> Tree<S> {
+    const ROOT: usize = usize::MAX;
+
+    fn push(&mut self, s: S, parent: usize) -> usize {
+        self.nodes.push((s, parent));
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut res = Vec::new();
+        while i != Self::ROOT {
+            res.push(self.nodes[i].0.clone());
+            i = self.nodes[i].1;
+        }
+        res.reverse();
+        res
+    }
+}
+
+/// Fewest steps from any of `starts` to a state satisfying `goal`
+pub fn bfs<S, K, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut key: impl FnMut(&S) -> K,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone,
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut tree = Tree{nodes: Vec::new()};
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    for s in starts {
+        if seen.insert(key(&s)) {
+            queue.push_back((tree.push(s, Tree::<S>::ROOT), 0));
+        }
+    }
+
+    while let Some((i, d)) = queue.pop_front() {
+        if goal(&tree.nodes[i].0) {
+            return Some(Found{cost: d, path: tree.path(i)});
+        }
+        for n in successors(&tree.nodes[i].0) {
+            if seen.insert(key(&n)) {
+                queue.push_back((tree.push(n, i), d + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest path from any of `starts` to a state satisfying `goal`, `successors` yield states with step costs
+pub fn dijkstra<S, K, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    key: impl FnMut(&S) -> K,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone,
+    K: Eq + Hash,
+    C: Int,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, key, successors, |_| C::ZERO, goal)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` which must never overestimate the remaining cost
+pub fn astar<S, K, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut key: impl FnMut(&S) -> K,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone,
+    K: Eq + Hash,
+    C: Int,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut tree = Tree{nodes: Vec::new()};
+    // cheapest known cost of every key, stale heap entries are skipped
+    let mut best = HashMap::<K, C>::new();
+    // ordered by estimated total cost, ties go to states closer to the goal
+    let mut heap = BinaryHeap::new();
+    for s in starts {
+        let h = heuristic(&s);
+        best.insert(key(&s), C::ZERO);
+        heap.push((Reverse(h), C::ZERO, Reverse(tree.push(s, Tree::<S>::ROOT))));
+    }
+
+    while let Some((_, cost, Reverse(i))) = heap.pop() {
+        if best.get(&key(&tree.nodes[i].0)).is_some_and(|&b| b < cost) {
+            continue;
+        }
+        if goal(&tree.nodes[i].0) {
+            return Some(Found{cost, path: tree.path(i)});
+        }
+        for (n, step) in successors(&tree.nodes[i].0) {
+            let c = cost + step;
+            match best.entry(key(&n)) {
+                Entry::Occupied(e) if *e.get() <= c => continue,
+                Entry::Occupied(mut e) => { e.insert(c); },
+                Entry::Vacant(e) => { e.insert(c); },
+            }
+            let h = heuristic(&n);
+            heap.push((Reverse(c + h), c, Reverse(tree.push(n, i))));
+        }
+    }
+    None
+}
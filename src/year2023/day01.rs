@@ -0,0 +1,53 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Error, Result};
+
+fn get_calibration_value(line: &str, digits: &[(&str, i32)]) -> Option<i32> {
+    let first = digits
+        .iter()
+        .filter_map(|&(pat, val)| line.find(pat).map(|index| (index, val)))
+        .min_by_key(|&(index, _)| index)
+        .map(|(_, d)| d)?;
+
+    let last = digits
+        .iter()
+        .filter_map(|&(pat, val)| line.rfind(pat).map(|index| (index, val)))
+        .max_by_key(|&(index, _)| index)
+        .map(|(_, d)| d)?;
+
+    Some(first*10 + last)
+}
+
+fn solve(data: &str, digits: Vec<(&str, i32)>) -> Result<i32> {
+    lines(data)
+        .map(|l| {
+            get_calibration_value(l.text, &digits)
+                .ok_or_else(|| Error::parse(l.no, 1, "no calibration digits in the line"))
+        })
+        .sum()
+}
+
+pub struct Puzzle {
+    data: String,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ data: data.to_string() })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve(&self.data, vec![
+            ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9), ("0", 0),
+        ]).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve(&self.data, vec![
+            ("one", 1), ("two", 2), ("three", 3), ("four", 4), ("five", 5), ("six", 6), ("seven", 7), ("eight", 8), ("nine", 9),
+            ("1", 1), ("2", 2), ("3", 3), ("4", 4), ("5", 5), ("6", 6), ("7", 7), ("8", 8), ("9", 9), ("0", 0),
+        ]).map(Answer::from)
+    }
+}
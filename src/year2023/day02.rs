@@ -0,0 +1,93 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Line, Result};
+
+struct CubeSet {
+    v: Vec<u64>
+}
+
+impl CubeSet {
+    fn new(line: &Line, s: &str) -> Result<Self> {
+        let mut v: Vec<u64> = vec![0; 3];
+        for str_set in s.split(", ") {
+            let (count, color) = str_set
+                .split_once(' ')
+                .ok_or_else(|| line.error(str_set, "expected `<count> <color>`"))?;
+            let count: u64 = line.parse(count)?;
+            let id = match color {
+                "red" =>   0,
+                "green" => 1,
+                "blue" =>  2,
+                _ => return Err(line.error(color, format!("unknown color `{}`", color))),
+            };
+            v[id] = count;
+        }
+
+        Ok(Self { v })
+    }
+}
+
+struct Game {
+    id: u64,
+    sets: Vec<CubeSet>,
+}
+
+impl Game {
+    fn new(line: &Line) -> Result<Self> {
+        let (game, sets) = line.split_once(": ")?;
+
+        let id: u64 = line.parse(game.trim_start_matches("Game"))?;
+        let sets: Vec<CubeSet> = sets
+            .split("; ")
+            .map(|s| CubeSet::new(line, s))
+            .collect::<Result<_>>()?;
+
+        Ok(Self{ id, sets })
+    }
+
+    fn get_power_set(&self, _: &CubeSet) -> u64 {
+        (0..3)
+            .map(|id| self.max_per_game(id))
+            .product()
+    }
+
+    fn max_per_game(&self, id: usize) -> u64 {
+        self.sets
+            .iter()
+            .map(|s| s.v[id])
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn is_game_possible(&self, s: &CubeSet) -> u64 {
+        if (0..3).all(|id| self.max_per_game(id) <= s.v[id]) { self.id } else { 0 }
+    }
+}
+
+fn solve(games: &[Game], s: &CubeSet, f: fn(&Game, &CubeSet)->u64) -> u64 {
+    games.iter().map(|game| f(game, s)).sum()
+}
+
+pub struct Puzzle {
+    games: Vec<Game>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    fn parse(data: &str) -> Result<Self> {
+        let games = lines(data)
+            .map(|l| Game::new(&l))
+            .collect::<Result<_>>()?;
+
+        Ok(Self{ games })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve(&self.games, &CubeSet{v: vec![12, 13, 14]}, Game::is_game_possible).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve(&self.games, &CubeSet{v: vec![]}, Game::get_power_set).into())
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
-use super::utils::Result;
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Error, Result};
 
 #[derive(Clone, PartialEq, Hash, Eq)]
 struct Number {
@@ -19,31 +20,33 @@ struct Symbol {
 
 type Symbols = HashMap<Symbol, Vec<Number>>;
 
-fn get_symbols(lines: &Vec<&str>) -> Symbols {
+fn get_symbols(lines: &[&str]) -> Result<Symbols> {
     let mut gs: Symbols = HashMap::new();
 
     for (y, &line) in lines.iter().enumerate() {
         let mut x = 0;
         while x < line.len() {
             let c = line.chars().nth(x).unwrap();
-            if c.is_digit(10) {
+            if c.is_ascii_digit() {
                 let mut cur = x + 1;
-                while cur < line.len() && line.chars().nth(cur).unwrap().is_digit(10) {
+                while cur < line.len() && line.chars().nth(cur).unwrap().is_ascii_digit() {
                     cur += 1;
                 }
 
-                let number = line[x..cur].parse().expect("Can not parse number");
+                let number = line[x..cur]
+                    .parse()
+                    .map_err(|_| Error::parse(y + 1, x + 1, "number is too large"))?;
                 let n = Number{x, y, value: number};
 
                 for ty in (y as i32)-1..=(y as i32)+1 {
                     if ty < 0 { continue; }
                     if ty >= lines.len() as i32 { break; }
 
-                    let &l = lines.iter().nth(ty as usize).unwrap();
+                    let &l = lines.get(ty as usize).unwrap();
                     for tx in (x as i32)-1..=(cur as i32) {
                         if tx >= 0 && tx < l.len() as i32 {
                             let cc = l.chars().nth(tx as usize).unwrap();
-                            if cc != '.' && !cc.is_digit(10) {
+                            if cc != '.' && !cc.is_ascii_digit() {
                                 let g = Symbol{x: tx, y: ty, c: cc};
                                 if let Some(v) = gs.get_mut(&g) {
                                     v.push(n.clone());
@@ -61,7 +64,7 @@ fn get_symbols(lines: &Vec<&str>) -> Symbols {
         }
     }
 
-    return gs;
+    Ok(gs)
 }
 
 fn solve1(symbols: &Symbols) -> u64 {
@@ -80,19 +83,30 @@ fn solve2(symbols: &Symbols) -> u64 {
 }
 
 
-pub fn run(data: &str, check: bool) -> Result {
-    let lines = data.split('\n').collect();
-    let symbols = get_symbols(&lines);
+pub struct Puzzle {
+    symbols: Symbols,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
 
-    let ans1 = solve1(&symbols);
-    println!("Part1: {}", ans1);
+    fn parse(data: &str) -> Result<Self> {
+        for l in lines(data) {
+            if let Some(c) = l.text.chars().find(|c| !c.is_ascii()) {
+                return Err(l.error(&l.text[l.text.find(c).unwrap()..], format!("unexpected `{}`", c)));
+            }
+        }
 
-    let ans2 = solve2(&symbols);
-    println!("Part2: {}", ans2);
+        let lines = data.split('\n').collect::<Vec<_>>();
+        Ok(Self{ symbols: get_symbols(&lines)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&self.symbols).into())
+    }
 
-    if !check || (ans1 == 528819 && ans2 == 80403602) {
-        Ok(())
-    } else {
-        Err(())
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve2(&self.symbols).into())
     }
 }
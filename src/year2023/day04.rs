@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Result};
+
+type N = i32;
+
+#[derive(Debug)]
+struct Card {
+    id: u32,
+    n: u32,
+}
+
+fn get_cards(data: &str) -> Result<Vec<Card>> {
+    lines(data)
+        .enumerate()
+        .map(|(i, line)| {
+            let (card, numbers) = line.split_once(":")?;
+            let id = line.parse::<u32>(card.trim_start_matches("Card"))?;
+            if id as usize != i + 1 {
+                return Err(line.error(card, format!("expected card {}", i + 1)));
+            }
+
+            let (winning, own) = numbers
+                .split_once('|')
+                .ok_or_else(|| line.error(numbers, "expected `|`"))?;
+            let sets = [winning, own]
+                .iter()
+                .map(|s|
+                    s
+                        .split(' ')
+                        .filter(|&x| !x.is_empty())
+                        .map(|x| line.parse::<N>(x))
+                        .collect::<Result<HashSet<_>>>()
+                )
+                .collect::<Result<Vec<_>>>()?;
+
+            let n = sets[0].intersection(&sets[1]).count() as u32;
+            Ok(Card{id: id - 1, n})
+        })
+        .collect()
+}
+
+fn solve1(cards: &[Card]) -> i32 {
+    cards
+        .iter()
+        .map(|c| {
+            if c.n == 0 {0} else {2_i32.pow(c.n - 1)}
+        })
+        .sum()
+}
+
+fn solve2(cards: &[Card]) -> u32 {
+    let mut counts: Vec<u32> = vec![1; cards.len()];
+
+    for c in cards {
+        for i in (c.id+1)..=(c.id+c.n).min(cards.len() as u32 - 1) {
+            counts[i as usize] += counts[c.id as usize];
+        }
+    }
+
+    counts.iter().sum()
+}
+
+pub struct Puzzle {
+    cards: Vec<Card>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ cards: get_cards(data)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&self.cards).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve2(&self.cards).into())
+    }
+}
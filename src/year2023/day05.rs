@@ -0,0 +1,111 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Error, Interval, IntervalSet, Line, RangeMap, Result};
+
+type Map = RangeMap<u64>;
+
+/// Rule mapping `<source>..<source>+<length>` onto `<destination>..`
+fn parse_rule(line: &Line) -> Result<(Interval<u64>, u64)> {
+    let v = line.text
+        .split(' ')
+        .map(|x| line.parse(x))
+        .collect::<Result<Vec<u64>>>()?;
+    let [to, from, len] = v[..] else {
+        return Err(line.error(line.text, "expected `<destination> <source> <length>`"));
+    };
+
+    Ok((Interval::with_len(from, len), to))
+}
+
+type Alm = Vec<Map>;
+
+struct Almanac {
+    maps: Alm,
+    raw_seeds: Vec<u64>,
+}
+
+impl Almanac {
+    fn new(data: &str) -> Result<Self> {
+        let mut maps = Alm::new();
+        let mut raw_seeds = Vec::<u64>::new();
+
+        let mut ranges = Map::new();
+
+        for line in lines(data) {
+            let l = line.text;
+            if l.is_empty() {
+                if !ranges.is_empty() {
+                    maps.push(std::mem::take(&mut ranges));
+                }
+                continue;
+            }
+            if l.starts_with("seeds:") {
+                raw_seeds = line.split_once(": ")?.1
+                    .split(' ')
+                    .map(|x| line.parse(x))
+                    .collect::<Result<_>>()?;
+                continue;
+            }
+            if l.ends_with("map:") {
+                continue;
+            }
+
+            let (from, to) = parse_rule(&line)?;
+            ranges.insert(from, to);
+        }
+        maps.push(ranges);
+
+        Ok(Almanac{maps, raw_seeds})
+    }
+
+}
+
+fn solve(seeds: IntervalSet<u64>, a: &Almanac) -> Result<u64> {
+    a.maps
+        .iter()
+        .fold(seeds, |cur, m| m.apply(&cur))
+        .min()
+        .ok_or_else(|| Error::unsolvable("no seeds"))
+}
+
+fn solve1(a: &Almanac) -> Result<u64> {
+    let seeds = a.raw_seeds
+        .iter()
+        .map(|&v| Interval::with_len(v, 1))
+        .collect();
+
+    solve(seeds, a)
+}
+
+fn solve2(a: &Almanac) -> Result<u64> {
+    if !a.raw_seeds.len().is_multiple_of(2) {
+        return Err(Error::unsolvable("seeds must come in `<start> <length>` pairs"));
+    }
+    let seeds = a.raw_seeds
+        .chunks(2)
+        .map(|v| Interval::with_len(v[0], v[1]))
+        .collect();
+
+    solve(seeds, a)
+}
+
+
+pub struct Puzzle {
+    almanac: Almanac,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ almanac: Almanac::new(data)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve1(&self.almanac).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.almanac).map(Answer::from)
+    }
+}
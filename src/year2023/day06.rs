@@ -0,0 +1,86 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Error, Result};
+
+
+fn get_dt(t: f64, s: f64) -> u64 {
+    let d = t*t - 4_f64*s;
+    if d <= 0.0 {
+        return 0;
+    }
+    let t1 = ((t - d.sqrt()) / 2.0_f64).floor() as u64 + 1;
+    let t2 = ((t + d.sqrt()) / 2.0_f64).ceil() as u64 - 1;
+    t2 - t1 + 1
+}
+
+fn solve(parts: &[Vec<u64>]) -> u64 {
+    parts[0]
+        .iter()
+        .zip(parts[1].iter())
+        .map(|(&t, &s)| get_dt(t as f64, s as f64))
+        .product()
+}
+
+fn solve1(parts: &[Vec<u64>]) -> u64 {
+    solve(parts)
+}
+
+fn solve2(parts: &[Vec<u64>]) -> Result<u64> {
+    let parts = parts
+        .iter()
+        .map(|p| {
+            p
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<String>()
+                .parse()
+                .map(|v| vec![v])
+                .map_err(|_| Error::unsolvable("joined number is too large"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(solve(&parts))
+}
+
+fn get_parts(data: &str) -> Result<Vec<Vec<u64>>> {
+    let parts = lines(data)
+        .zip(["Time:", "Distance:"])
+        .map(|(line, title)| {
+            let values = line.text
+                .strip_prefix(title)
+                .ok_or_else(|| line.error(line.text, format!("expected `{}`", title)))?;
+            values
+                .split(' ')
+                .filter(|&x| !x.is_empty())
+                .map(|v| line.parse(v))
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if parts.len() != 2 || parts[0].len() != parts[1].len() {
+        let last = lines(data).last().unwrap();
+        return Err(last.error(last.text, "expected `Time:` and `Distance:` lines of equal length"));
+    }
+    Ok(parts)
+}
+
+
+pub struct Puzzle {
+    parts: Vec<Vec<u64>>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ parts: get_parts(data)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&self.parts).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.parts).map(Answer::from)
+    }
+}
@@ -1,22 +1,30 @@
-use super::utils::Result;
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Line, Result};
 
 use std::{collections::HashMap, cmp::Ordering};
 
-#[derive(Debug)]
-struct Hand<'a> {
-    cards: &'a str,
+#[derive(Debug, Clone)]
+struct Hand {
+    cards: String,
     counts: Vec<u8>,
     counts_j: Vec<u8>,
     bid: u64
 }
 
-impl<'a> Hand<'a> {
-    fn new(s: &'a str) -> Self {
-        let p = s.split(' ').collect::<Vec<_>>();
+impl Hand {
+    fn new(line: &Line) -> Result<Self> {
+        let (cards, bid) = line.split_once(" ")?;
+        if cards.chars().count() != 5 {
+            return Err(line.error(cards, "a hand must have 5 cards"));
+        }
+        if let Some(c) = cards.chars().find(|&c| !"AKQJT98765432".contains(c)) {
+            return Err(line.error(cards, format!("unknown card `{}`", c)));
+        }
+
         let mut counts_map = HashMap::<char, u8>::new();
         let mut counts_j_map = HashMap::<char, u8>::new();
         let mut nj: u8 = 0;
-        for c in p[0].chars() {
+        for c in cards.chars() {
             if let Some(n) = counts_map.get_mut(&c) {
                 *n += 1 ;
             } else {
@@ -36,29 +44,29 @@ impl<'a> Hand<'a> {
         let mut counts_j = counts_j_map.into_values().collect::<Vec<_>>();
 
         if nj > 0 {
-            if counts_j.len() == 0 {
+            if counts_j.is_empty() {
                 counts_j.push(5);
             } else {
                 let max = counts_j.iter().max().unwrap().to_owned();
                 let max_pos = counts_j.iter().position(|&x| x == max).unwrap();
-                counts_j[max_pos] += nj as u8;
+                counts_j[max_pos] += nj;
             }
         } else {
             counts_j = counts.clone();
         }
 
-        Hand{cards: p[0], counts, bid: p[1].parse().unwrap(), counts_j}
+        Ok(Hand{cards: cards.to_string(), counts, bid: line.parse(bid)?, counts_j})
     }
 }
 
 
 struct HandComaprator {
     cards: Vec<char>,
-    get_counts_: for<'a> fn(h: &'a Hand<'a>) -> &'a Vec<u8>,
+    get_counts_: fn(h: &Hand) -> &Vec<u8>,
 }
 
 impl HandComaprator {
-    fn get_counts<'a>(&'a self, h: &'a Hand) -> &Vec<u8> {
+    fn get_counts<'a>(&self, h: &'a Hand) -> &'a Vec<u8> {
         (self.get_counts_)(h)
     }
 }
@@ -81,72 +89,71 @@ fn order(cards1: &str, cards2: &str, cmp: &HandComaprator) -> Ordering {
         .chars()
         .zip(cards2.chars())
         .map(|(c1, c2)| compare(c1, c2, cmp))
-        .filter(|&i| i != 0)
-        .next().unwrap();
-    order.cmp(&1)
+        .find(|&i| i != 0)
+        .unwrap_or(0);
+    order.cmp(&0)
 }
 
-fn is_five(counts: &Vec<u8>) -> u8 {
+fn is_five(counts: &[u8]) -> u8 {
     let five: u8 = 5;
     if counts.contains(&five) {
         7
     } else { 0 }
 }
-fn is_four(counts: &Vec<u8>) -> u8 {
+fn is_four(counts: &[u8]) -> u8 {
     let four: u8 = 4;
     if counts.contains(&four) {
         6
     } else { 0 }
 }
-fn is_full(counts: &Vec<u8>) -> u8 {
+fn is_full(counts: &[u8]) -> u8 {
     let three: u8 = 3;
     let two: u8 = 2;
     if counts.contains(&three) && counts.contains(&two) {
         5
     } else { 0 }
 }
-fn is_three(counts: &Vec<u8>) -> u8 {
+fn is_three(counts: &[u8]) -> u8 {
     let one: u8 = 1;
     let three: u8 = 3;
-    if counts.iter().filter(|&x| x.to_owned() == one).count() == 2 && counts.iter().filter(|&x| x.to_owned() == three).count() == 1 {
+    if counts.iter().filter(|&x| *x == one).count() == 2 && counts.iter().filter(|&x| *x == three).count() == 1 {
         4
     } else { 0 }
 }
-fn is_two_pairs(counts: &Vec<u8>) -> u8 {
+fn is_two_pairs(counts: &[u8]) -> u8 {
     let two: u8 = 2;
-    if counts.iter().filter(|&x| x.to_owned() == two).count() == 2 {
+    if counts.iter().filter(|&x| *x == two).count() == 2 {
         3
     } else { 0 }
 }
-fn is_one_pair(counts: &Vec<u8>) -> u8 {
+fn is_one_pair(counts: &[u8]) -> u8 {
     let two: u8 = 2;
-    if counts.iter().filter(|&x| x.to_owned() == two).count() == 1 {
+    if counts.iter().filter(|&x| *x == two).count() == 1 {
         2
     } else { 0 }
 }
-fn is_high(counts: &Vec<u8>) -> u8 {
+fn is_high(counts: &[u8]) -> u8 {
     let one: u8 = 1;
-    if counts.iter().filter(|&x| x.to_owned() == one).count() == 5 {
+    if counts.iter().filter(|&x| *x == one).count() == 5 {
         1
     } else { 0 }
 }
 
 fn get_rank(h: &Hand, cmp: &HandComaprator) -> u8 {
-    let fs = vec![is_five, is_four, is_full, is_three, is_two_pairs, is_one_pair, is_high];
+    let fs = [is_five, is_four, is_full, is_three, is_two_pairs, is_one_pair, is_high];
 
     fs
         .iter()
         .map(|f| f(cmp.get_counts(h)))
-        .filter(|&x| x > 0)
-        .next().unwrap().to_owned()
+        .find(|&x| x > 0).unwrap().to_owned()
 }
 
-fn solve(hands: & mut Vec<Hand>, cmp: &HandComaprator) -> u64 {
+fn solve(hands: &mut [Hand], cmp: &HandComaprator) -> u64 {
     hands
         .sort_by(|h1, h2| {
             let r1 = get_rank(h1, cmp);
             let r2 = get_rank(h2, cmp);
-            if r1 != r2 { r1.cmp(&r2) } else { order(h1.cards, h2.cards, cmp) }
+            if r1 != r2 { r1.cmp(&r2) } else { order(&h1.cards, &h2.cards, cmp) }
         });
 
     hands
@@ -156,31 +163,37 @@ fn solve(hands: & mut Vec<Hand>, cmp: &HandComaprator) -> u64 {
 }
 
 
-pub fn run(data: &str, check: bool) -> Result {
-    let mut hands = data
-        .split('\n')
-        .map(|s| Hand::new(s))
-        .collect();
+pub struct Puzzle {
+    hands: Vec<Hand>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    fn parse(data: &str) -> Result<Self> {
+        let hands = lines(data)
+            .map(|line| Hand::new(&line))
+            .collect::<Result<_>>()?;
 
-    let cmp1 = HandComaprator{
-        cards: vec!['A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2'],
-        get_counts_: |h: &Hand| &h.counts,
-    };
+        Ok(Self{ hands })
+    }
 
-    let ans1 = solve(&mut hands, &cmp1);
-    println!("Part1: {}", ans1);
+    fn part1(&self) -> Result<Answer> {
+        let cmp = HandComaprator{
+            cards: vec!['A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2'],
+            get_counts_: |h: &Hand| &h.counts,
+        };
 
-    let cmp2 = HandComaprator{
-        cards: vec!['A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J'],
-        get_counts_: |h: &Hand| &h.counts_j,
-    };
+        Ok(solve(&mut self.hands.clone(), &cmp).into())
+    }
 
-    let ans2 = solve(&mut hands, &cmp2);
-    println!("Part2: {}", ans2);
+    fn part2(&self) -> Result<Answer> {
+        let cmp = HandComaprator{
+            cards: vec!['A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J'],
+            get_counts_: |h: &Hand| &h.counts_j,
+        };
 
-    if !check || (ans1 == 250453939 && ans2 == 248652697) {
-        Ok(())
-    } else {
-        Err(())
+        Ok(solve(&mut self.hands.clone(), &cmp).into())
     }
 }
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use crate::solver::{Answer, Solver};
+use crate::utils::num::crt;
+use crate::utils::{lines, Error, Line, Result};
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    l: String,
+    r: String,
+}
+
+impl Node {
+    fn new(line: &Line) -> Result<Self> {
+        let (name, choices) = line.split_once(" = ")?;
+        let (l, r) = choices
+            .strip_prefix('(')
+            .and_then(|c| c.strip_suffix(')'))
+            .and_then(|c| c.split_once(", "))
+            .ok_or_else(|| line.error(choices, "expected `(<left>, <right>)`"))?;
+        Ok(Node{name: name.to_string(), l: l.to_string(), r: r.to_string()})
+    }
+}
+
+fn parse_input(data: &str) -> Result<(String, HashMap<String, Node>)> {
+    let mut lines = lines(data).filter(|l| !l.text.is_empty());
+    let Some(first) = lines.next() else {
+        return Err(Error::parse(1, 1, "expected the path"));
+    };
+    if let Some(i) = first.text.find(|c| c != 'L' && c != 'R') {
+        return Err(first.error(&first.text[i..], "path must consist of `L` and `R`"));
+    }
+
+    let nodes = lines
+        .map(|line| {
+            let node = Node::new(&line)?;
+            Ok((node.name.clone(), node))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((first.text.to_string(), nodes))
+}
+
+fn count(path: &str, nodes: &HashMap<String, Node>, start: &str, is_end: fn(&str)->bool) -> Result<u64> {
+    // no end reached after visiting every node in every path position means a loop
+    let limit = (path.len() * (nodes.len() + 1)) as u64;
+    let mut cur = start;
+    let mut n: u64 = 0;
+    for s in path.chars().cycle() {
+        let node = nodes.get(cur).ok_or_else(|| Error::unsolvable(format!("unknown node {}", cur)))?;
+        if s == 'L' { cur = &node.l; } else { cur = &node.r; }
+        n += 1;
+        if is_end(cur) {
+            break;
+        }
+        if n > limit {
+            return Err(Error::unsolvable(format!("no end is reachable from {}", start)));
+        }
+    }
+
+    Ok(n)
+}
+
+/// Steps at which a ghost stands on an end node
+struct Ghost {
+    /// Hits before the ghost enters its loop
+    transient: Vec<i64>,
+    /// Hits during the first pass of the loop
+    hits: Vec<i64>,
+    loop_start: i64,
+    loop_len: i64,
+}
+
+impl Ghost {
+    fn new(path: &str, nodes: &HashMap<String, Node>, start: &str, is_end: fn(&str)->bool) -> Result<Self> {
+        let path = path.as_bytes();
+        let mut seen = HashMap::<(&str, usize), i64>::new();
+        let mut ends = Vec::new();
+        let mut cur = start;
+        let mut n: i64 = 0;
+        let loop_start = loop {
+            let i = n as usize % path.len();
+            if let Some(&prev) = seen.get(&(cur, i)) {
+                break prev;
+            }
+            seen.insert((cur, i), n);
+            if is_end(cur) {
+                ends.push(n);
+            }
+
+            let node = nodes.get(cur).ok_or_else(|| Error::unsolvable(format!("unknown node {}", cur)))?;
+            cur = if path[i] == b'L' { &node.l } else { &node.r };
+            n += 1;
+        };
+
+        let (transient, hits): (Vec<_>, Vec<_>) = ends.into_iter().partition(|&t| t < loop_start);
+        if hits.is_empty() {
+            return Err(Error::unsolvable(format!("no end is reachable from {} forever", start)));
+        }
+        Ok(Self{ transient, hits, loop_start, loop_len: n - loop_start })
+    }
+
+    fn at_end(&self, t: i64) -> bool {
+        if t < self.loop_start {
+            self.transient.contains(&t)
+        } else {
+            self.hits.contains(&(self.loop_start + (t - self.loop_start) % self.loop_len))
+        }
+    }
+}
+
+fn solve1(path: &str, nodes: &HashMap<String, Node>) -> Result<u64> {
+    count(path, nodes, "AAA", |n| n == "ZZZ")
+}
+
+fn solve2(path: &str, nodes: &HashMap<String, Node>) -> Result<u64> {
+    let ghosts = nodes
+        .keys()
+        .filter(|n| n.ends_with('A'))
+        .map(|s| Ghost::new(path, nodes, s, |n| n.ends_with('Z')))
+        .collect::<Result<Vec<_>>>()?;
+    if ghosts.is_empty() {
+        return Err(Error::unsolvable("no start nodes"));
+    }
+
+    // hits before some ghost loops come earlier than any shared hit inside all loops
+    let transient = ghosts
+        .iter()
+        .flat_map(|g| g.transient.iter().copied())
+        .filter(|&t| ghosts.iter().all(|g| g.at_end(t)))
+        .min();
+    if let Some(t) = transient {
+        return Ok(t as u64);
+    }
+
+    let after = ghosts.iter().map(|g| g.loop_start).max().unwrap();
+    let mut best: Option<i64> = None;
+    let mut choice = vec![0; ghosts.len()];
+    loop {
+        let congruences = ghosts.iter().zip(&choice).map(|(g, &i)| (g.hits[i], g.loop_len));
+        if let Some((r, m)) = crt(congruences) {
+            let t = if r >= after { r } else { r + (after - r + m - 1) / m * m };
+            best = Some(best.map_or(t, |b| b.min(t)));
+        }
+
+        // next combination of one hit per ghost
+        let Some(k) = (0..ghosts.len()).find(|&k| choice[k] + 1 < ghosts[k].hits.len()) else {
+            break;
+        };
+        choice[k] += 1;
+        choice[..k].iter_mut().for_each(|c| *c = 0);
+    }
+
+    best
+        .map(|t| t as u64)
+        .ok_or_else(|| Error::unsolvable("ghosts never reach their ends at the same time"))
+}
+
+pub struct Puzzle {
+    path: String,
+    nodes: HashMap<String, Node>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    fn parse(data: &str) -> Result<Self> {
+        let (path, nodes) = parse_input(data)?;
+
+        Ok(Self{ path, nodes })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve1(&self.path, &self.nodes).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.path, &self.nodes).map(Answer::from)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Result};
+
+type I = i32;
+
+fn diff(nums: &[I]) -> Vec<I> {
+    nums
+        .iter()
+        .skip(1)
+        .zip(nums.iter())
+        .map(|(&a, &b)| a - b)
+        .collect()
+}
+
+fn get_diffs(nums: &[I]) -> Vec<Vec<I>> {
+    let mut res = vec![nums.to_vec()];
+
+    let mut cur = nums.to_vec();
+    loop {
+        cur = diff(&cur);
+        res.push(cur.clone());
+        if cur.iter().all(|&x| x == 0) { return res; }
+    }
+}
+
+fn get_next(diffs: &[Vec<I>]) -> I {
+    let mut n = 0;
+    for v in diffs.iter().rev() {
+        n += v.last().unwrap_or(&0);
+    }
+    n
+}
+
+fn get_prev(diffs: &[Vec<I>]) -> I {
+    let mut n = 0;
+    for v in diffs.iter().rev() {
+        n = v.first().unwrap_or(&0) - n;
+    }
+    n
+}
+
+fn solve(nums: &[Vec<I>], get: fn(&[Vec<I>])->I) -> I {
+    nums
+        .iter()
+        .map(|nums| get(&get_diffs(nums)))
+        .sum()
+}
+
+pub struct Puzzle {
+    nums: Vec<Vec<I>>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    fn parse(data: &str) -> Result<Self> {
+        let nums = lines(data)
+            .map(|line| line.text.split(' ').map(|n| line.parse(n)).collect())
+            .collect::<Result<_>>()?;
+
+        Ok(Self{ nums })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve(&self.nums, get_next).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve(&self.nums, get_prev).into())
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Dir, Error, Grid, Point, Polygon, Result};
+
+type I = i16;
+type Pos = Point<I>;
+
+fn get_next(p: &Pos, dir: Dir, grid: &Grid<char>) -> Option<Pos> {
+    let np = *p + dir.delta();
+    grid.contains(np).then_some(np)
+}
+
+#[derive(Debug)]
+struct Pipe {
+    /// Directions the pipe leads to, in the order of `Dir::ALL`
+    ends: Vec<Dir>,
+}
+
+impl Pipe {
+    fn create() -> HashMap<char, Self> {
+        HashMap::from([
+            ('|', Pipe{ends: vec![Dir::Up, Dir::Down]}),
+            ('-', Pipe{ends: vec![Dir::Right, Dir::Left]}),
+            ('L', Pipe{ends: vec![Dir::Up, Dir::Right]}),
+            ('J', Pipe{ends: vec![Dir::Up, Dir::Left]}),
+            ('7', Pipe{ends: vec![Dir::Down, Dir::Left]}),
+            ('F', Pipe{ends: vec![Dir::Right, Dir::Down]}),
+            ('.', Pipe{ends: vec![]}),
+        ])
+    }
+
+    fn connected(&self, o: &Pipe, dir: Dir) -> bool {
+        self.ends.contains(&dir) && o.ends.contains(&dir.opposite())
+    }
+}
+
+struct Maze {
+    data: Grid<char>,
+    start: Pos,
+    start_char: char,
+    pipes: HashMap<char, Pipe>,
+}
+
+impl Maze {
+    fn new(data: &str) -> Result<Self> {
+        let grid = Grid::parse(lines(data), "|-LJ7F.S")?;
+        let pipes = Pipe::create();
+        let start = Self::get_start(&grid)?;
+
+        let start_ends = Dir::ALL
+            .into_iter()
+            .filter(|&dir| {
+                get_next(&start, dir, &grid).is_some_and(|next| pipes[&grid[next]].ends.contains(&dir.opposite()))
+            })
+            .collect::<Vec<_>>();
+
+        let start_char = pipes
+            .iter()
+            .find(|(&k, v)| k != '.' && v.ends == start_ends)
+            .map(|(&k, _)| k)
+            .ok_or_else(|| Error::unsolvable("start must be connected to exactly two pipes"))?;
+
+        Ok(Maze {
+            data: grid,
+            start, start_char, pipes,
+        })
+    }
+
+    fn get_start(maze: &Grid<char>) -> Result<Pos> {
+        let mut starts = maze.positions(|&c| c == 'S');
+        match (starts.next(), starts.next()) {
+            (Some(start), None) => Ok(start),
+            (None, _) => Err(Error::unsolvable("can not find start position")),
+            (Some(_), Some(_)) => Err(Error::unsolvable("more than one start position")),
+        }
+    }
+
+    fn get_pipe(&self, p: &Pos) -> &Pipe {
+        if *p == self.start {
+            self.pipes.get(&self.start_char).unwrap()
+        } else {
+            self.pipes.get(&self.data[*p]).unwrap()
+        }
+    }
+
+    fn collect_cycle(&self) -> Result<Vec<Pos>> {
+        let mut path = vec![self.start];
+
+        let mut cur = self.start;
+        loop {
+            let cur_p = self.get_pipe(&cur);
+            let next = Dir::ALL
+                .into_iter()
+                .find_map(|dir| {
+                    if let Some(next) = get_next(&cur, dir, &self.data) {
+                        let p = self.get_pipe(&next);
+                        if cur_p.connected(p, dir)
+                            && (path.len() == 1 || (path.len() > 1 && path[path.len()-2] != next)) {
+                                return Some(next);
+                            }
+                    }
+                    None
+                })
+                .ok_or_else(|| Error::unsolvable(format!("loop is broken at {:?}", cur)))?;
+
+            path.push(next);
+            cur = next;
+            if cur == self.start {
+                return Ok(path);
+            }
+        }
+    }
+}
+
+fn solve1(maze: &Maze) -> Result<usize> {
+    let p = maze.collect_cycle()?;
+    Ok((p.len() - 1) / 2)
+}
+
+fn solve2(maze: &Maze) -> Result<usize> {
+    // the loop runs through tile centers, so enclosed tiles are the lattice points inside it
+    let mut path = maze.collect_cycle()?;
+    path.pop();
+    let tiles = Polygon::new(path.into_iter().map(|p| p.cast()).collect());
+
+    Ok(tiles.interior() as usize)
+}
+
+pub struct Puzzle {
+    maze: Maze,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ maze: Maze::new(data)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve1(&self.maze).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.maze).map(Answer::from)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Grid, Point, Result};
+
+type Pos = Point<usize>;
+
+struct Universe {
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    galaxies: Vec<Pos>,
+}
+
+impl Universe {
+    fn new(image: &Grid<char>) -> Self {
+        let rows = image
+            .rows().enumerate()
+            .filter(|(_, s)| !s.contains(&'#'))
+            .map(|(i, _)| i)
+            .collect();
+
+        let cols = (0..image.width())
+            .filter(|&i| image.column(i).all(|&c| c != '#'))
+            .collect();
+
+        let galaxies = image.positions(|&c| c == '#').collect();
+
+        Universe{rows, cols, galaxies}
+    }
+
+    fn get_dist(&self, g1: &Pos, g2: &Pos, m: usize) -> usize {
+        let dx = (g1.x.min(g2.x)..g1.x.max(g2.x))
+            .filter(|i| self.cols.contains(i)).count();
+        let dy = (g1.y.min(g2.y)..g1.y.max(g2.y))
+            .filter(|i| self.rows.contains(i)).count();
+
+        g1.manhattan(g2) + (dx + dy)*(m-1)
+    }
+
+}
+
+
+fn solve(universe: &Universe, m: usize) -> usize {
+    universe.galaxies
+        .iter().enumerate()
+        .flat_map(|(i, g1)| {
+            universe.galaxies.iter().skip(i+1)
+                .map(|g2| universe.get_dist(g1, g2, m))
+        })
+        .sum()
+}
+
+pub struct Puzzle {
+    universe: Universe,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    fn parse(data: &str) -> Result<Self> {
+        let image = Grid::parse(lines(data), ".#")?;
+        Ok(Self{ universe: Universe::new(&image) })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve(&self.universe, 2).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve(&self.universe, 1000000).into())
+    }
+}
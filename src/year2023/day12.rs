@@ -0,0 +1,107 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Line, Result};
+
+
+#[derive(Debug)]
+struct Record {
+    springs: String,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    fn new(line: &Line) -> Result<Self> {
+        let (springs, groups) = line.split_once(" ")?;
+        if let Some(i) = springs.find(|c| !"?.#".contains(c)) {
+            return Err(line.error(&springs[i..], "unknown spring condition"));
+        }
+        let groups = groups
+            .split(',')
+            .map(|s| match line.parse(s)? {
+                0 => Err(line.error(s, "group size must be positive")),
+                g => Ok(g),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self{springs: springs.to_string(), groups})
+    }
+}
+
+#[derive(Debug)]
+struct Pattern {
+    line: String,
+    groups: Vec<usize>,
+}
+
+impl Pattern {
+    fn new(r: &Record, n: usize) -> Self {
+        let line = format!(".{}", vec![r.springs.as_str(); n].join("?").trim_end_matches('.'));
+        let groups = r.groups.repeat(n);
+
+        Self{line, groups}
+    }
+
+    fn count(&self) -> usize {
+        let mut dp = vec![0; self.line.len()+1];
+        dp[0] = 1;
+        for (i, _) in self.line.chars().take_while(|&c| c != '#').enumerate() {
+            dp[i+1] = 1;
+        }
+
+        for &g in self.groups.iter() {
+            let mut dp_next = vec![0; self.line.len()+1];
+            let mut chunk = 0;
+            for (i, c) in self.line.chars().enumerate() {
+                if c != '.' {
+                    chunk += 1;
+                } else {
+                    chunk = 0;
+                }
+
+                if c != '#' {
+                    dp_next[i+1] += dp_next[i];
+                }
+
+                if chunk >= g && i >= g && self.line.chars().nth(i-g).unwrap() != '#' {
+                    dp_next[i+1] += dp[i-g];
+                }
+            }
+            dp = dp_next;
+        }
+
+        *dp.last().unwrap()
+    }
+}
+
+
+fn solve(records: &[Record], n: usize) -> usize {
+    records
+        .iter()
+        .map(|r| Pattern::new(r, n).count())
+        .sum()
+}
+
+
+pub struct Puzzle {
+    records: Vec<Record>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+
+    fn parse(data: &str) -> Result<Self> {
+        let records = lines(data)
+            .map(|line| Record::new(&line))
+            .collect::<Result<_>>()?;
+
+        Ok(Self{ records })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve(&self.records, 1).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve(&self.records, 5).into())
+    }
+}
@@ -1,20 +1,18 @@
-use super::utils::Result;
+use crate::solver::{Answer, Solver};
+use crate::utils::{sections, Error, Grid, Line, Result};
 
 struct Pattern {
-    field: Vec<Vec<char>>,
+    field: Grid<char>,
     w: usize,
     h: usize,
 }
 
 impl Pattern {
-    fn new(data: Vec<&str>) -> Self {
-        let field = data
-            .iter()
-            .map(|&s| s.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-        let w = field[0].len();
-        let h = field.len();
-        Self{field, w, h}
+    fn new(data: Vec<Line>) -> Result<Self> {
+        let field = Grid::parse(data, ".#")?;
+        let w = field.width();
+        let h = field.height();
+        Ok(Self{field, w, h})
     }
 
     fn count_diff<T: std::cmp::PartialEq>(i1: impl Iterator<Item=T>, i2: impl Iterator<Item=T>) -> usize {
@@ -22,10 +20,10 @@ impl Pattern {
     }
 
     fn row_iter(&self, r: usize) -> impl Iterator<Item=&char> + '_ {
-        self.field[r].iter()
+        self.field.row(r).iter()
     }
     fn col_iter(&self, c: usize) -> impl Iterator<Item=&char> + '_ {
-        self.field.iter().map(move |l| l.iter().nth(c).unwrap())
+        self.field.column(c)
     }
 
     fn rows_diff(&self, r1: usize, r2: usize) -> usize {
@@ -48,7 +46,7 @@ impl Pattern {
         n == max
     }
 
-    fn get_reflection(&self, max: usize) -> usize {
+    fn get_reflection(&self, max: usize) -> Result<usize> {
         let mut row = self.h;
         let mut col = self.w;
 
@@ -66,55 +64,51 @@ impl Pattern {
         }
 
         if row == self.h && col == self.w {
-            panic!("can not find reflection");
+            return Err(Error::unsolvable("can not find reflection"));
         }
 
-        if row < self.h { 100*(row+1) } else { col+1 }
+        Ok(if row < self.h { 100*(row+1) } else { col+1 })
     }
 }
 
-fn read(data: &str) -> Vec<Pattern> {
-    let mut res = Vec::<Pattern>::new();
-    let mut cur = Vec::<&str>::new();
-    for line in data.split('\n') {
-        if line.is_empty() {
-            res.push(Pattern::new(cur));
-            cur = Vec::<&str>::new();
-        } else {
-            cur.push(line);
-        }
-    }
-    if !cur.is_empty() {
-        res.push(Pattern::new(cur));
-    }
-
-    res
+fn read(data: &str) -> Result<Vec<Pattern>> {
+    sections(data)
+        .into_iter()
+        .map(Pattern::new)
+        .collect()
 }
 
-fn solve1(data: &str) -> usize {
-    read(data)
+fn solve1(patterns: &[Pattern]) -> Result<usize> {
+    patterns
         .iter()
         .map(|p| p.get_reflection(0))
         .sum()
 }
 
-fn solve2(data: &str) -> usize {
-    read(data)
+fn solve2(patterns: &[Pattern]) -> Result<usize> {
+    patterns
         .iter()
         .map(|p| p.get_reflection(1))
         .sum()
 }
 
-pub fn run(data: &str, check: bool) -> Result {
-    let ans1 = solve1(data);
-    println!("Part1: {}", ans1);
+pub struct Puzzle {
+    patterns: Vec<Pattern>,
+}
 
-    let ans2 = solve2(data);
-    println!("Part2: {}", ans2);
+impl Solver for Puzzle {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ patterns: read(data)? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve1(&self.patterns).map(Answer::from)
+    }
 
-    if !check || (ans1 == 30575 && ans2 == 37478) {
-        Ok(())
-    } else {
-        Err(())
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.patterns).map(Answer::from)
     }
 }
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
-
-use super::utils::{Result, Point};
+use crate::solver::{Answer, Solver};
+use crate::utils::cycle;
+use crate::utils::{lines, Grid, Point, Result};
 
 type I = i32;
 type Pos = Point<I>;
@@ -9,23 +9,20 @@ type Diff = Point<I>;
 const ROCK: char = 'O';
 const EMPTY: char = '.';
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 struct Platform {
-    table: Vec<Vec<char>>,
+    table: Grid<char>,
     w: I,
     h: I,
 }
 
 impl Platform {
-    fn new(lines: &Vec<&str>) -> Self {
-        let table = lines
-            .iter()
-            .map(|&s| s.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>();
-        let w = table[0].len() as I;
-        let h = table.len() as I;
-
-        Self{table, w, h}
+    fn new(data: &str) -> Result<Self> {
+        let table = Grid::parse(lines(data), ".#O")?;
+        let w = table.width() as I;
+        let h = table.height() as I;
+
+        Ok(Self{table, w, h})
     }
 
     fn tilts() -> &'static [fn(&mut Self)] {
@@ -33,11 +30,11 @@ impl Platform {
     }
 
     fn move_if_can(&mut self, from: &mut Pos, d: &Diff) -> bool {
-        let to = from.add(d);
-        let can = self.table[from.y as usize][from.x as usize] == ROCK && self.table[to.y as usize][to.x as usize] == EMPTY;
+        let to = *from + *d;
+        let can = self.table[*from] == ROCK && self.table[to] == EMPTY;
         if can {
-            self.table[from.y as usize][from.x as usize] = EMPTY;
-            self.table[to.y as usize][to.x as usize] = ROCK;
+            self.table[*from] = EMPTY;
+            self.table[to] = ROCK;
             *from = to;
         }
         can
@@ -89,17 +86,10 @@ impl Platform {
 
     fn calc_load(&self) -> usize {
         self.table
-            .iter().enumerate()
+            .rows().enumerate()
             .map(|(i, l)| (self.h as usize - i) * l.iter().filter(|&&c| c == 'O').count())
             .sum()
     }
-
-    fn hash(&self) -> String {
-        self.table
-            .iter()
-            .flatten()
-            .collect::<String>()
-    }
 }
 
 fn solve1(p: &mut Platform) -> usize {
@@ -107,48 +97,27 @@ fn solve1(p: &mut Platform) -> usize {
     p.calc_load()
 }
 
-fn solve2(p: &mut Platform) -> usize {
-    let mut h = HashMap::<String, usize>::new();
-    let mut v = Vec::<Platform>::new();
-
-    #[allow(unused_assignments)]
-    let mut period = 0;
-
-    #[allow(unused_assignments)]
-    let mut start = 0;
-
-    let mut i = 0;
-    loop {
-        p.cycle();
-        v.push(p.clone());
-        let hash = p.hash();
-        if let Some(&ip) = h.get(&hash) {
-            start = ip;
-            period = i - ip;
-            break;
-        } else {
-            h.insert(hash, i);
-        }
-        i += 1;
-    }
+fn solve2(p: &Platform) -> usize {
+    cycle::nth_by_hash(p.clone(), Platform::cycle, 1000000000).calc_load()
+}
 
-    let x = (1000000000 - start) % period;
-    v[start + x -1].calc_load()
+pub struct Puzzle {
+    platform: Platform,
 }
 
-pub fn run(data: &str, check: bool) -> Result {
-    let lines = data.split('\n').collect();
-    let platform = Platform::new(&lines);
+impl Solver for Puzzle {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
 
-    let ans1 = solve1(&mut platform.clone());
-    println!("Part1: {}", ans1);
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ platform: Platform::new(data)? })
+    }
 
-    let ans2 = solve2(&mut platform.clone());
-    println!("Part2: {}", ans2);
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&mut self.platform.clone()).into())
+    }
 
-    if !check || (ans1 == 108889 && ans2 == 104671) {
-        Ok(())
-    } else {
-        Err(())
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve2(&self.platform).into())
     }
 }
@@ -0,0 +1,117 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Line, Result};
+
+const HASH_MOD: usize = 256;
+const HASH_P: usize = 17;
+
+#[derive(Debug)]
+struct Lens<'a> {
+    name: &'a str,
+    focal: usize,
+}
+
+#[derive(Debug)]
+struct Op {
+    name: String,
+    op: char,
+    val: usize,
+}
+
+impl Op {
+    fn new(line: &Line, s: &str) -> Result<Self> {
+        let (name, op, val) = if let Some((name, val)) = s.split_once('=') {
+            (name, '=', line.parse(val)?)
+        } else if let Some(name) = s.strip_suffix('-') {
+            (name, '-', 0)
+        } else {
+            return Err(line.error(s, "expected `<label>=<focal length>` or `<label>-`"));
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(line.error(s, format!("invalid label `{}`", name)));
+        }
+
+        Ok(Op{name: name.to_string(), op, val})
+    }
+
+    fn lens(&self) -> Lens<'_> {
+        Lens{name: &self.name, focal: self.val}
+    }
+}
+
+type LensBox<'a> = Vec<Lens<'a>>;
+
+fn hash(s: &str) -> usize {
+    s.chars().fold(0, |acc, c| ((acc + c as usize) * HASH_P)%HASH_MOD)
+}
+
+fn solve1(steps: &[String]) -> usize {
+    steps
+        .iter()
+        .map(|s| hash(s))
+        .sum()
+}
+
+fn solve2(ops: &[Op]) -> usize {
+    let mut boxes = Vec::<LensBox>::new();
+    for _ in 0..HASH_MOD {
+        boxes.push(LensBox::new());
+    }
+
+    for op in ops {
+        let b = &mut boxes[hash(&op.name)];
+
+        let opt_id = b.iter().position(|l| l.name == op.name);
+        match op.op {
+            '-' => {
+                if let Some(i) = opt_id { b.remove(i); }
+            },
+            '=' => {
+                if let Some(i) = opt_id {
+                    b[i].focal = op.val;
+                } else {
+                    b.push(op.lens());
+                }
+            }
+            _ => panic!("unreachable")
+        }
+    }
+
+    boxes
+        .iter().enumerate()
+        .map(|(bid, b)| {
+            (1 + bid) * b.iter().enumerate().map(|(lid, lens)| (lid + 1) * lens.focal).sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Puzzle {
+    steps: Vec<String>,
+    ops: Vec<Op>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+
+    fn parse(data: &str) -> Result<Self> {
+        let mut steps = Vec::new();
+        let mut ops = Vec::new();
+        // newlines are ignored, the sequence may be wrapped
+        for line in lines(data) {
+            for s in line.text.split(',').filter(|s| !s.is_empty()) {
+                ops.push(Op::new(&line, s)?);
+                steps.push(s.to_string());
+            }
+        }
+
+        Ok(Self{ steps, ops })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&self.steps).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve2(&self.ops).into())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::utils;
+
+use crate::solver::{Answer, Solver};
+use crate::utils::search::Bfs;
+use crate::utils::{lines, Dir, Grid, Result};
+
+type Pos = utils::Point<i32>;
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+struct Cell {
+    p: Pos,
+    d: Dir,
+}
+
+impl Cell {
+    fn add(&self, d: Dir) -> Self { Self{ p: self.p + d.delta(), d} }
+
+    /// Cells the beam moves on to, only those inside the grid
+    fn next<'a>(&self, table: &'a Grid<char>) -> impl Iterator<Item = Cell> + 'a {
+        let (d, split) = match table[self.p] {
+            '|' if self.d.is_horizontal() => (Dir::Down, Some(Dir::Up)),
+            '-' if self.d.is_vertical() => (Dir::Left, Some(Dir::Right)),
+            '.' | '|' | '-' => (self.d, None),
+            // the beam turns left when moving horizontally and right when moving vertically
+            '/' if self.d.is_horizontal() => (self.d.turn_left(), None),
+            '/' => (self.d.turn_right(), None),
+            '\\' if self.d.is_horizontal() => (self.d.turn_right(), None),
+            '\\' => (self.d.turn_left(), None),
+            c => unreachable!("tile `{}` at {:?} is neither empty nor a mirror or a splitter", c, self.p),
+        };
+        let cell = *self;
+        std::iter::once(d).chain(split).map(move |d| cell.add(d)).filter(|n| table.contains(n.p))
+    }
+}
+
+fn count_energized(table: &Grid<char>, start: Cell) -> usize {
+    // a single number is much cheaper to hash than the cell
+    let w = table.width();
+    let key = |c: &Cell| ((c.p.y as usize * w + c.p.x as usize) << 2) | c.d.index();
+
+    let mut energized = Grid::new(table.width(), table.height(), false);
+    for (c, _) in Bfs::new([start], key, |c: &Cell| c.next(table)) {
+        energized[c.p] = true;
+    }
+    energized.rows().flatten().filter(|&&e| e).count()
+}
+
+fn solve1(table: &Grid<char>) -> usize {
+    count_energized(table, Cell{p: Pos{x: 0, y: 0}, d: Dir::Right})
+}
+
+fn solve2(table: &Grid<char>) -> usize {
+    let mut res = 0;
+    let h = table.height() as i32;
+    let w = table.width()  as i32;
+
+    for c in 0..w {
+        res = res
+            .max(count_energized(table, Cell {p: Pos{x: c, y:   0}, d: Dir::Down}))
+            .max(count_energized(table, Cell {p: Pos{x: c, y: h-1}, d: Dir::Up}));
+    }
+    for r in 0..h {
+        res = res
+            .max(count_energized(table, Cell {p: Pos{x: 0,   y: r}, d: Dir::Right}))
+            .max(count_energized(table, Cell {p: Pos{x: w-1, y: r}, d: Dir::Left}));
+    }
+
+    res
+}
+
+pub struct Puzzle {
+    table: Grid<char>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ table: Grid::parse(lines(data), ".|-/\\")? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&self.table).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve2(&self.table).into())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::search::astar;
+use crate::utils::{lines, Dir, Error, Grid, Point, Result};
+
+
+type N = i32;
+type Pos = Point::<N>;
+
+/// Crucible position, its direction and the number of blocks moved in that direction
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Cell {
+    p: Pos,
+    d: Dir,
+    run: usize,
+}
+
+impl Cell {
+    fn step(&self, d: Dir, table: &Grid<N>) -> Option<(Cell, N)> {
+        let p = self.p + d.delta();
+        let run = if d == self.d { self.run + 1 } else { 1 };
+        table.get(p).map(|&loss| (Cell{p, d, run}, loss))
+    }
+
+    fn next(&self, table: &Grid<N>, minn: usize, maxn: usize) -> impl Iterator<Item = (Cell, N)> {
+        let ahead = if self.run < maxn { self.step(self.d, table) } else { None };
+        let (right, left) = if self.run >= minn {
+            (self.step(self.d.turn_right(), table), self.step(self.d.turn_left(), table))
+        } else {
+            (None, None)
+        };
+        [ahead, right, left].into_iter().flatten()
+    }
+}
+
+fn solve(table: &Grid<N>, minn: usize, maxn: usize) -> Result<N> {
+    let finish = Pos{
+        x: (table.width() - 1) as N,
+        y: (table.height() - 1) as N,
+    };
+    if finish == (Pos{x: 0, y: 0}) {
+        return Ok(0);
+    }
+
+    let starts = [Dir::Right, Dir::Down].map(|d| Cell{p: Pos::default(), d, run: 0});
+    astar(
+        starts,
+        |c| *c,
+        |c| c.next(table, minn, maxn),
+        |c| c.p.manhattan(&finish),
+        |c| c.p == finish && c.run >= minn,
+    )
+        .map(|found| found.cost)
+        .ok_or_else(|| Error::unsolvable("can not reach the factory"))
+}
+
+pub struct Puzzle {
+    table: Grid<N>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Clumsy Crucible";
+
+    fn parse(data: &str) -> Result<Self> {
+        let table = Grid::parse(lines(data), "0123456789")?.map(|&c| c as N - '0' as N);
+
+        Ok(Self{ table })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve(&self.table, 1, 3).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve(&self.table, 4, 10).map(Answer::from)
+    }
+}
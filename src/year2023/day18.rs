@@ -0,0 +1,79 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Dir, Line, Polygon, Result};
+
+type N = i64;
+
+/// Direction encoded in the last digit of a color
+fn color_dir(c: char) -> Option<Dir> {
+    match c {
+        '0' => Some(Dir::Right),
+        '1' => Some(Dir::Down),
+        '2' => Some(Dir::Left),
+        '3' => Some(Dir::Up),
+        _ => None
+    }
+}
+
+struct Move {
+    d: Dir,
+    n: N,
+}
+
+impl Move {
+    /// Plain move and the one encoded in its color
+    fn new(line: &Line) -> Result<(Self, Self)> {
+        let parts = line.text.split(' ').collect::<Vec<_>>();
+        let [d, n, color] = parts[..] else {
+            return Err(line.error(line.text, "expected `<direction> <length> (#<color>)`"));
+        };
+
+        let d = match d {
+            "R" | "D" | "L" | "U" => Dir::from_char(line.char_at(0)?).unwrap(),
+            _ => return Err(line.error(d, format!("unknown direction `{}`", d))),
+        };
+        let n = line.parse(n)?;
+
+        let hex = color
+            .strip_prefix("(#")
+            .and_then(|c| c.strip_suffix(')'))
+            .filter(|c| c.len() == 6 && c.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| line.error(color, "expected `(#<6 hex digits>)`"))?;
+        let colored = color_dir(hex.chars().nth(5).unwrap())
+            .map(|cd| Self{d: cd, n: N::from_str_radix(&hex[..5], 16).unwrap()})
+            .ok_or_else(|| line.error(&hex[5..], "color must end with a direction 0-3"))?;
+
+        Ok((Self{d, n}, colored))
+    }
+}
+
+fn solve(moves: &[Move]) -> N {
+    Polygon::from_moves(moves.iter().map(|m| (m.d, m.n))).lattice_points()
+}
+
+pub struct Puzzle {
+    moves: Vec<Move>,
+    colored_moves: Vec<Move>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Lavaduct Lagoon";
+
+    fn parse(data: &str) -> Result<Self> {
+        let (moves, colored_moves) = lines(data)
+            .map(|line| Move::new(&line))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        Ok(Self{ moves, colored_moves })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve(&self.moves).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve(&self.colored_moves).into())
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use crate::solver::{Answer, Solver};
+use crate::utils::{sections, Error, Interval, IntervalBox, Line, Result};
+
+struct Xmas {
+    d: HashMap<char, u64>,
+}
+impl Xmas {
+    fn new(line: &Line) -> Result<Self> {
+        let s = line.text
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| line.error(line.text, "expected `{x=..,m=..,a=..,s=..}`"))?;
+        let d = s
+            .split(",")
+            .map(|p| {
+                let (c, v) = p.split_once('=').ok_or_else(|| line.error(p, "expected `<category>=<rating>`"))?;
+                Ok((category(line, c)?, line.parse::<u64>(v)?))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        if d.len() != 4 {
+            return Err(line.error(s, "expected all of x, m, a and s ratings"));
+        }
+
+        Ok(Self{d})
+    }
+}
+
+const CATEGORIES: &str = "xmas";
+
+/// Ratings of every category, indexed by their position in `CATEGORIES`
+type XmasRanges = IntervalBox<u64, 4>;
+
+struct Rule {
+    c: char,
+    op: char,
+    v: u64,
+    to: String,
+}
+impl Rule {
+    fn new(line: &Line, s: &str) -> Result<Self> {
+        if let Some((r, to)) = s.split_once(':') {
+            let Some(i) = r.find(['<', '>']) else {
+                return Err(line.error(r, "expected `<category><op><rating>`"));
+            };
+            let c = category(line, &r[..i])?;
+            let op = r[i..].chars().next().unwrap();
+            let v = line.parse(&r[i+1..])?;
+
+            Ok(Self{c, op, v, to: to.to_string()})
+        } else {
+            Ok(Self{to: s.to_string(), c: 'x', op: '\0', v: 0})
+        }
+    }
+    fn apply(&self, p: &Xmas) -> Option<&String> {
+        let v = *p.d.get(&self.c).unwrap();
+        let ok = match self.op {
+            '>' => v > self.v,
+            '<' => v < self.v,
+            _ => true,
+        };
+        if ok { Some(&self.to) } else { None }
+    }
+
+    fn from_line(line: &Line) -> Result<(String, Vec<Self>)> {
+        let (name, rules_str) = line.split_once("{")?;
+        let rules_str = rules_str
+            .strip_suffix('}')
+            .ok_or_else(|| line.error(&line.text[line.text.len()..], "expected `}`"))?;
+
+        let rules = rules_str
+            .split(",")
+            .map(|s| Rule::new(line, s))
+            .collect::<Result<Vec<_>>>()?;
+        if rules.last().is_some_and(|r| r.op != '\0') {
+            return Err(line.error(rules_str, "the last rule must be unconditional"));
+        }
+
+        Ok((name.to_string(), rules))
+    }
+
+    /// Ratings matching the rule and the ones left for the next rules
+    fn split_ranges(&self, pr: &XmasRanges) -> (Option<XmasRanges>, Option<XmasRanges>) {
+        let dim = CATEGORIES.find(self.c).unwrap();
+        match self.op {
+            '>' => {
+                let (rest, matched) = pr.split_at(dim, self.v.saturating_add(1));
+                (matched, rest)
+            },
+            '<' => pr.split_at(dim, self.v),
+            _ => (Some(*pr), None),
+        }
+    }
+}
+
+type Workflows = HashMap<String, Vec<Rule>>;
+
+fn apply_rules(mut ranges: XmasRanges, rules: &[Rule]) -> Vec<(String, XmasRanges)> {
+    let mut res = Vec::new();
+
+    for rule in rules.iter() {
+        let (matched, tail) = rule.split_ranges(&ranges);
+        if let Some(matched) = matched {
+            res.push((rule.to.clone(), matched));
+        }
+        if let Some(tail) = tail {
+            ranges = tail;
+        } else {
+            break;
+        }
+    }
+    res
+}
+
+
+fn category(line: &Line, c: &str) -> Result<char> {
+    match c {
+        "x" | "m" | "a" | "s" => Ok(c.chars().next().unwrap()),
+        _ => Err(line.error(c, format!("unknown category `{}`", c))),
+    }
+}
+
+fn get_rules<'a>(workflows: &'a Workflows, name: &str) -> Result<&'a [Rule]> {
+    workflows
+        .get(name)
+        .map(Vec::as_slice)
+        .ok_or_else(|| Error::unsolvable(format!("unknown workflow {}", name)))
+}
+
+fn solve1(workflows: &Workflows, items: &[Xmas]) -> Result<u64> {
+    let mut s = 0;
+    for xmas in items.iter() {
+        let mut cur = &"in".to_string();
+        for steps in 0.. {
+            if steps > workflows.len() {
+                return Err(Error::unsolvable("workflows loop"));
+            }
+            let rules = get_rules(workflows, cur)?;
+            for r in rules.iter() {
+                if let Some(next) = r.apply(xmas) {
+                    cur = next;
+                    break;
+                }
+            }
+            if cur.as_str() == "A" || cur.as_str() == "R" { break; }
+        }
+
+        if cur.as_str() == "A" {
+            s += xmas.d.values().sum::<u64>();
+        }
+    }
+
+    Ok(s)
+}
+
+fn solve2(workflows: &Workflows, min: u64, max: u64) -> Result<u64> {
+    let start = XmasRanges::new([Interval::inclusive(min, max); 4]);
+
+    let mut pool = vec![("in".to_string(), start, 0)];
+    let mut res = 0;
+    while let Some((name, pr, steps)) = pool.pop() {
+        if name.as_str() == "A" {
+            res += pr.volume();
+            continue;
+        }
+        if name.as_str() == "R" { continue; }
+        if steps > workflows.len() {
+            return Err(Error::unsolvable("workflows loop"));
+        }
+
+        let next = apply_rules(pr, get_rules(workflows, &name)?);
+        pool.extend(next.into_iter().map(|(name, pr)| (name, pr, steps + 1)));
+    }
+    Ok(res)
+}
+
+
+fn parse(data: &str) -> Result<(Workflows, Vec<Xmas>)> {
+    let p = sections(data);
+    let [workflows, items] = &p[..] else {
+        return Err(Error::parse(1, 1, "expected workflows and ratings separated by an empty line"));
+    };
+
+    let workflows = workflows
+        .iter()
+        .map(Rule::from_line)
+        .collect::<Result<_>>()?;
+
+    let items = items
+        .iter()
+        .map(Xmas::new)
+        .collect::<Result<_>>()?;
+
+    Ok((workflows, items))
+}
+
+pub struct Puzzle {
+    workflows: Workflows,
+    items: Vec<Xmas>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Aplenty";
+
+    fn parse(data: &str) -> Result<Self> {
+        let (workflows, items) = parse(data)?;
+        Ok(Self{ workflows, items })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve1(&self.workflows, &self.items).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.workflows, 1, 4000).map(Answer::from)
+    }
+}
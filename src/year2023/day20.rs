@@ -1,10 +1,14 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use super::utils::{Result, lcm};
+use crate::solver::{Answer, Solver};
+use crate::utils::num::crt;
+use crate::utils::{lines, Error, Line, Result};
 
-const BROADCASTER: &'static str = "broadcaster";
+const BROADCASTER: &str = "broadcaster";
+const RX: &str = "rx";
 
-#[derive(Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Clone)]
 enum ModuleType {
     FLIPFLOP,
     CONJUNCTION,
@@ -12,7 +16,8 @@ enum ModuleType {
     NONE,
 }
 
-#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone)]
 enum ModuleState {
     ON,
     OFF,
@@ -27,6 +32,7 @@ impl ModuleState {
     }
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Signal {
     LOW,
@@ -34,7 +40,7 @@ enum Signal {
     NONE,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Module {
     typ: ModuleType,
     state: ModuleState,
@@ -45,22 +51,21 @@ struct Module {
 type Childs = HashMap::<String, Vec<String>>;
 
 impl Module {
-    fn new(s: &str) -> (String, Module, Vec<String>) {
-        let mut parts = s.split(" -> ");
-        let from = parts.next().unwrap();
+    fn new(line: &Line) -> Result<(String, Module, Vec<String>)> {
+        let (from, to) = line.split_once(" -> ")?;
         let (typ, name) = if from == BROADCASTER {
             (ModuleType::BROADCAST, from.to_string())
         } else {
-            let mt = match from.chars().nth(0).unwrap() {
-                '%' => ModuleType::FLIPFLOP,
-                '&' => ModuleType::CONJUNCTION,
-                _ => unreachable!()
+            let mt = match from.chars().next() {
+                Some('%') => ModuleType::FLIPFLOP,
+                Some('&') => ModuleType::CONJUNCTION,
+                _ => return Err(line.error(from, "expected `broadcaster`, `%<name>` or `&<name>`"))
             };
             (mt, from[1..].to_string())
         };
-        let to = parts.next().unwrap().split(", ").map(|s| s.to_string()).collect::<Vec<_>>();
+        let to = to.split(", ").map(|s| s.to_string()).collect::<Vec<_>>();
         let mem = HashMap::new();
-        (name, Module{typ, state: ModuleState::OFF, input: Signal::NONE, output: Signal::NONE, mem}, to)
+        Ok((name, Module{typ, state: ModuleState::OFF, input: Signal::NONE, output: Signal::NONE, mem}, to))
     }
 
     fn reset(&mut self) {
@@ -79,7 +84,7 @@ impl Module {
             },
             ModuleType::BROADCAST => {
                 self.input = input;
-                self.output = self.input.clone();
+                self.output = self.input;
                 false
             },
             ModuleType::FLIPFLOP => {
@@ -99,7 +104,7 @@ impl Module {
             ModuleType::CONJUNCTION => {
                 self.input = input;
                 let mem_in = self.mem.get_mut(from).unwrap();
-                 *mem_in = self.input.clone();
+                 *mem_in = self.input;
                  self.output = if self.mem.values().all(|s| s == &Signal::HIGH) {
                     Signal::LOW
                 } else {
@@ -116,21 +121,25 @@ impl Default for Module {
     }
 }
 
+#[derive(Clone)]
 struct Modules {
     m: HashMap<String, Module>,
 }
 impl Modules {
-    fn new(lines: &Vec<&str>) -> (Self, Childs, String) {
+    fn new(data: &str) -> Result<(Self, Childs, String)> {
         let mut m = HashMap::new();
         let mut childs = HashMap::new();
 
-        lines
-            .iter()
-            .map(|&s| Module::new(s))
-            .for_each(|(name, module, to)| {
-                m.insert(name.clone(), module);
-                childs.insert(name, to);
-            });
+        for line in lines(data) {
+            let (name, module, to) = Module::new(&line)?;
+            if m.insert(name.clone(), module).is_some() {
+                return Err(line.error(line.text, format!("module {} is already defined", name)));
+            }
+            childs.insert(name, to);
+        }
+        if !m.contains_key(BROADCASTER) {
+            return Err(Error::unsolvable("there is no broadcaster module"));
+        }
 
         let all = childs
             .values()
@@ -150,7 +159,7 @@ impl Modules {
         let mut modules = Modules{m};
         modules.prepare_conj(&childs);
 
-        (modules, childs, BROADCASTER.to_string())
+        Ok((modules, childs, BROADCASTER.to_string()))
     }
     fn prepare_conj(&mut self, childs: &Childs) {
         let mut conj = HashMap::<String, Vec<String>>::new();
@@ -178,17 +187,17 @@ impl Modules {
 
         let mut nlow = 1;
         let mut nhigh = 0;
+        let mut found = false;
 
-        let mut q = VecDeque::<&String>::new();
-        q.push_back(&start);
-        while !q.is_empty() {
-            let from = q.pop_front().unwrap();
-            let output = self.m.get(from).unwrap().output.clone();
+        // pulses carry the signal sent, a module may change its output before they are delivered
+        let mut q = VecDeque::<(&String, Signal)>::new();
+        q.push_back((start, self.m.get(start).unwrap().output));
+        while let Some((from, output)) = q.pop_front() {
             if output == Signal::NONE {
                 continue;
             }
             if from == name && output == desired {
-                return (n, true, (nlow, nhigh));
+                found = true;
             }
             for to in childs.get(from).unwrap().iter() {
                 match output {
@@ -197,39 +206,61 @@ impl Modules {
                     _ => unreachable!(),
                 }
 
-                if !self.processed(from, to) {
-                    q.push_back(to);
+                if !self.m.get_mut(to).unwrap().processed(output, from) {
+                    q.push_back((to, self.m.get(to).unwrap().output));
                 }
             }
             self.m.get_mut(from).unwrap().input = Signal::NONE;
         }
-        (n, false, (nlow, nhigh))
-    }
-
-    fn processed(&mut self, from: &String, to: &String) -> bool {
-        let input = self.m.get_mut(from).unwrap().output.clone();
-        self.m.get_mut(to).unwrap().processed(input, from)
+        (n, found, (nlow, nhigh))
     }
 
     fn reset(&mut self) {
         self.m.values_mut().for_each(|m| m.reset());
     }
 
-    fn count_presses(&mut self, childs: &Childs, start: &String, name: &String, desired: Signal) -> u64 {
-        self.reset();
+    /// Flip-flop states and conjunction memories, all a press depends on
+    fn state(&self) -> Vec<bool> {
+        self.m
+            .values()
+            .flat_map(|m| std::iter::once(matches!(m.state, ModuleState::ON)).chain(m.mem.values().map(|&s| s == Signal::HIGH)))
+            .collect()
+    }
+
+    /// Presses the button until `name` sends `desired` and returns the number of presses, fails once
+    /// the modules get back to a state seen since the last `seen.clear()` without sending it
+    fn press_until(&mut self, childs: &Childs, start: &String, name: &String, desired: Signal, seen: &mut HashSet<Vec<bool>>) -> Result<i64> {
         let mut n = 1;
-        while !self.press_button(&childs, start, n, name, desired).1 {
+        seen.insert(self.state());
+        while !self.press_button(childs, start, n, name, desired).1 {
+            if !seen.insert(self.state()) {
+                return Err(Error::unsolvable(format!("module {} never sends a {:?} pulse", name, desired)));
+            }
             n += 1;
         }
-        n
+        Ok(n as i64)
+    }
+
+    /// Press at which `name` first sends `desired` and the number of presses until it does again
+    fn count_presses(&mut self, childs: &Childs, start: &String, name: &String, desired: Signal) -> Result<(i64, i64)> {
+        self.reset();
+        let mut seen = HashSet::new();
+        let first = self.press_until(childs, start, name, desired, &mut seen)?;
+        // a pulse sent only before the modules start to repeat their states is not sent again
+        seen.clear();
+        let period = self.press_until(childs, start, name, desired, &mut seen)?;
+        Ok((first, period))
     }
 
     fn get_main_conjunctions(&self, childs: &Childs, start: &String) -> HashSet<String> {
         let mut results = HashSet::new();
+        let mut seen = HashSet::new();
 
         let mut stack = vec![start];
-        while !stack.is_empty() {
-            let cur = stack.pop().unwrap();
+        while let Some(cur) = stack.pop() {
+            if !seen.insert(cur) {
+                continue;
+            }
             match self.m.get(cur).unwrap().typ {
                 ModuleType::CONJUNCTION => {
                     let _ = results.insert(cur);
@@ -247,34 +278,58 @@ impl Modules {
 
 fn solve1(modules: &mut Modules, childs: &Childs, start: &String) -> u64 {
     let (low, high) = (0..1000)
-        .map(|n| modules.press_button(&childs, start, n, &"".to_string(), Signal::NONE).2)
+        .map(|n| modules.press_button(childs, start, n, &"".to_string(), Signal::NONE).2)
         .reduce(|(al, ah), (l, h)| (al+l, ah+h))
         .unwrap();
     low * high
 }
 
-fn solve2(modules: &mut Modules, childs: &Childs, start: &String) -> u64 {
-    modules.get_main_conjunctions(&childs, start)
+fn solve2(modules: &mut Modules, childs: &Childs, start: &String) -> Result<u64> {
+    let feeders = childs.iter().filter(|(_, to)| to.iter().any(|t| t == RX)).map(|(name, _)| name).collect::<Vec<_>>();
+    match feeders[..] {
+        [] => return Err(Error::unsolvable("there is no rx module")),
+        [f] if modules.m[f].typ == ModuleType::CONJUNCTION => {},
+        _ => return Err(Error::unsolvable("rx module is not fed by a single conjunction")),
+    }
+
+    let cycles = modules.get_main_conjunctions(childs, start)
         .iter()
-        .map(|name| modules.count_presses(&childs, start, name, Signal::LOW))
-        .reduce(|acc, n| lcm(acc, n))
-        .unwrap()
+        .map(|name| modules.count_presses(childs, start, name, Signal::LOW))
+        .collect::<Result<Vec<_>>>()?;
+    if cycles.is_empty() {
+        return Err(Error::unsolvable("no conjunction modules are reachable from the broadcaster"));
+    }
+
+    let (r, m) = crt(cycles.iter().copied())
+        .ok_or_else(|| Error::unsolvable("conjunction modules never fire in the same press"))?;
+
+    // every conjunction has to fire at least once, a zero residue means the full period
+    let first = cycles.iter().map(|&(first, _)| first).max().unwrap();
+    let n = if r >= first { r } else { r + (first - r + m - 1) / m * m };
+    Ok(n as u64)
 }
 
-pub fn run(data: &str, check: bool) -> Result {
-    let lines = data.split('\n').collect();
-    let (mut modules, childs, start) = Modules::new(&lines);
+pub struct Puzzle {
+    modules: Modules,
+    childs: Childs,
+    start: String,
+}
 
-    let ans1 = solve1(&mut modules, &childs, &start);
-    println!("Part1: {}", ans1);
+impl Solver for Puzzle {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Pulse Propagation";
 
-    let ans2 = solve2(&mut modules, &childs, &start);
-    println!("Part2: {}", ans2);
+    fn parse(data: &str) -> Result<Self> {
+        let (modules, childs, start) = Modules::new(data)?;
 
-    if !check || (ans1 == 800830848 && ans2 == 244055946148853) {
-        Ok(())
-    } else {
-        Err(())
+        Ok(Self{ modules, childs, start })
     }
-}
 
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&mut self.modules.clone(), &self.childs, &self.start).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&mut self.modules.clone(), &self.childs, &self.start).map(Answer::from)
+    }
+}
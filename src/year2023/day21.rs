@@ -0,0 +1,83 @@
+use crate::solver::{Answer, Solver};
+use crate::utils::search::Bfs;
+use crate::utils::{lines, Dir, Error, Grid, Point, Result};
+
+type I = i32;
+type Pos = Point<I>;
+
+fn nbrs(p: Pos) -> impl Iterator<Item = Pos> {
+    Dir::ALL.into_iter().map(move |d| p + d.delta())
+}
+
+fn available1(p: &Pos, f: &Grid<char>) -> bool {
+    f.get(*p).is_some_and(|&c| c != '#')
+}
+
+fn available2(p: &Pos, f: &Grid<char>) -> bool {
+    f.wrapped(*p).is_some_and(|&c| c != '#')
+}
+
+fn get_start(field: &Grid<char>) -> Result<Pos> {
+    field.find(&'S').ok_or_else(|| Error::unsolvable("can not find start position"))
+}
+
+/// Number of plots reachable in exactly 1..=n steps
+fn count_steps(field: &Grid<char>, start: &Pos, av: fn(&Pos, &Grid<char>)->bool, n: usize) -> Vec<usize> {
+    // a plot first reached in d steps is reachable again every second step after that
+    let mut first = vec![0; n + 1];
+    Bfs::new([*start], |p| *p, |&p| nbrs(p).filter(|q| av(q, field)))
+        .take_while(|&(_, d)| d <= n)
+        .for_each(|(_, d)| first[d] += 1);
+
+    (1..=n)
+        .map(|k| (k % 2..=k).step_by(2).map(|d| first[d]).sum())
+        .collect()
+}
+
+fn solve1(field: &Grid<char>, n: usize) -> Result<usize> {
+    Ok(count_steps(field, &get_start(field)?, available1, n)[n-1])
+}
+
+fn quad(v1: usize, v2: usize, v3: usize, n: usize) -> usize {
+    // the middle term is subtracted, so intermediate values can be negative
+    let (v1, v2, v3, n) = (v1 as i128, v2 as i128, v3 as i128, n as i128);
+    (v1*(n-1)*(n-2)/2 - v2*n*(n-2) + v3*n*(n-1)/2) as usize
+}
+
+fn solve2(field: &Grid<char>, n: usize) -> Result<usize> {
+    let start = get_start(field)?;
+
+    let h = field.height();
+    let half = (h - 1) / 2;
+    // the reachable count grows quadratically only for a square field with the start in its center
+    // and the number of steps reaching exactly the edge of some tile
+    if h < 3 || field.width() != h || start != (Pos{x: half as I, y: half as I}) || !(n - half).is_multiple_of(h) {
+        return Err(Error::unsolvable("field must be square with the start in its center"));
+    }
+    let maxn = 2*h + half;
+
+    let res = count_steps(field, &start, available2, maxn);
+
+    Ok(quad(res[half-1], res[half-1+h], res[half-1+2*h], (n-half)/h))
+}
+
+pub struct Puzzle {
+    field: Grid<char>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "Step Counter";
+
+    fn parse(data: &str) -> Result<Self> {
+        Ok(Self{ field: Grid::parse(lines(data), ".#S")? })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve1(&self.field, 64).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.field, 26501365).map(Answer::from)
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Cuboid, Line, Point3, Result};
+
+type U = u32;
+type Pos = Point3<U>;
+
+const UP: Pos = Pos{x: 0, y: 0, z: 1};
+
+#[derive(Debug)]
+struct Brick {
+    extent: Cuboid<U>,
+    above: HashSet<usize>,
+    below: HashSet<usize>,
+}
+
+impl Brick {
+    fn new(line: &Line) -> Result<Self> {
+        let (first, second) = line.split_once("~")?;
+        let p1 = Pos::parse(line, first)?;
+        let p2 = Pos::parse(line, second)?;
+        if p1.x > p2.x || p1.y > p2.y || p1.z > p2.z {
+            return Err(line.error(second, "the second end must not be less than the first one"));
+        }
+        if p1.z == 0 {
+            return Err(line.error(first, "bricks must be above the ground"));
+        }
+
+        Ok(Self{extent: Cuboid{min: p1, max: p2}, above: HashSet::new(), below: HashSet::new()})
+    }
+
+    fn prepare(&mut self, h: &HashMap<Pos, usize>) {
+        self.above = ids(self.extent.top() + UP, h);
+        self.below = ids(self.extent.bottom() - UP, h);
+    }
+
+    fn drop(&mut self, h: &mut HashMap<Pos, usize>, id: &usize) {
+        while self.extent.min.z > 1 && ids(self.extent.bottom() - UP, h).is_empty() {
+            self.extent = self.extent - UP;
+        }
+
+        h.extend(self.extent.points().map(|p| (p, *id)));
+    }
+
+    fn can_be_desintegrated(&self, bricks: &HashMap<usize, Brick>) -> bool {
+        self.above
+            .iter()
+            .all(|id| {
+                let b = bricks.get(id).unwrap();
+                b.below.len() > 1
+            })
+    }
+}
+
+/// Bricks occupying any point of `layer`
+fn ids(layer: Cuboid<U>, h: &HashMap<Pos, usize>) -> HashSet<usize> {
+    layer.points().filter_map(|p| h.get(&p).copied()).collect()
+}
+
+type Bricks = HashMap<usize, Brick>;
+
+fn prepare(data: &str) -> Result<(Bricks, Vec<usize>)> {
+    let mut bricks = lines(data)
+        .enumerate()
+        .map(|(id, line)| Ok((id, Brick::new(&line)?)))
+        .collect::<Result<HashMap<usize, Brick>>>()?;
+
+    let mut sorted_ids  = bricks
+        .iter()
+        .map(|(&id, _)| id)
+        .collect::<Vec<usize>>();
+    sorted_ids.sort_by(|i1, i2| {
+        let b1 = bricks.get(i1).unwrap();
+        let b2 = bricks.get(i2).unwrap();
+        b1.extent.min.z.cmp(&b2.extent.min.z)
+    });
+
+    let mut h = HashMap::<Pos, usize>::new();
+
+    sorted_ids
+        .iter()
+        .for_each(|id| bricks.get_mut(id).unwrap().drop(&mut h, id));
+
+    sorted_ids
+        .iter()
+        .for_each(|id| bricks.get_mut(id).unwrap().prepare(&h));
+
+    Ok((bricks, sorted_ids))
+}
+
+fn solve1(bricks: &HashMap<usize, Brick>, sorted_ids: &[usize]) -> usize {
+    sorted_ids
+        .iter()
+        .map(|id| bricks.get(id).unwrap().can_be_desintegrated(bricks))
+        .filter(|&f| f)
+        .count()
+}
+
+fn count_falls(bricks: &HashMap<usize, Brick>, id: &usize) -> usize {
+    let mut falls = HashSet::<usize>::from_iter([*id]);
+
+    let mut q = vec![*id];
+    while let Some(i) = q.pop() {
+        for above_id in bricks.get(&i).unwrap().above.iter() {
+            if falls.is_superset(&bricks.get(above_id).unwrap().below) {
+                q.push(*above_id);
+                falls.insert(*above_id);
+            }
+        }
+    }
+    falls.len() - 1
+}
+
+fn solve2(bricks: &HashMap<usize, Brick>, sorted_ids: &[usize]) -> usize {
+    sorted_ids
+        .iter()
+        .map(|id| count_falls(bricks, id))
+        .sum()
+}
+
+pub struct Puzzle {
+    bricks: HashMap<usize, Brick>,
+    sorted_ids: Vec<usize>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Sand Slabs";
+
+    fn parse(data: &str) -> Result<Self> {
+        let (bricks, sorted_ids) = prepare(data)?;
+
+        Ok(Self{ bricks, sorted_ids })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(solve1(&self.bricks, &self.sorted_ids).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(solve2(&self.bricks, &self.sorted_ids).into())
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use crate::solver::{Answer, Solver};
+use crate::utils::{lines, Error, Grid, Point, Result};
+
+type U = usize;
+type Pos = Point<U>;
+
+/// Forks of the trails together with the start and the finish, joined by the lengths of the corridors
+/// between them. With `slopes` a corridor can only be walked down its slopes, otherwise both ways.
+struct Junctions {
+    /// `(junction, length)` of the corridors leaving every junction
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    finish: usize,
+    /// The finish is reached by a single corridor
+    dead_end: bool,
+}
+
+impl Junctions {
+    fn new(map: &Grid<char>, start: Pos, finish: Pos, slopes: bool) -> Self {
+        let open = |p: Pos| map.neighbours4(p).filter(move |&n| map[n] != '#');
+        let can_step = |from: Pos, to: Pos| !slopes || match map[from] {
+            '>' => to.x > from.x,
+            'v' => to.y > from.y,
+            _ => true,
+        };
+        let mut points = vec![start, finish];
+        points.extend(map.positions(|&c| c != '#').filter(|&p| p != start && p != finish && open(p).count() > 2));
+        let index = points.iter().enumerate().map(|(i, &p)| (p, i)).collect::<HashMap<_, _>>();
+
+        let edges = points
+            .iter()
+            .map(|&p| {
+                open(p)
+                    .filter(|&first| can_step(p, first))
+                    .filter_map(|first| {
+                        // corridors have no forks, so there is only one way on until the next junction
+                        let (mut prev, mut cur, mut len) = (p, first, 1);
+                        while !index.contains_key(&cur) {
+                            let next = open(cur).find(|&n| n != prev).filter(|&n| can_step(cur, n))?;
+                            (prev, cur, len) = (cur, next, len + 1);
+                        }
+                        (cur != p).then(|| (index[&cur], len))
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+
+        let dead_end = edges.iter().flatten().filter(|&&(n, _)| n == 1).count() == 1;
+        Self{ edges, start: 0, finish: 1, dead_end }
+    }
+
+    /// Longest path from `node` to the finish avoiding the `visited` junctions
+    fn longest(&self, node: usize, visited: u64, len: usize, rest: usize, best: &mut Option<usize>) {
+        if node == self.finish {
+            *best = (*best).max(Some(len));
+            return;
+        }
+        // a finish with a single corridor is a dead end, once next to it any other way cuts it off
+        if self.dead_end {
+            if let Some(&(_, d)) = self.edges[node].iter().find(|&&(n, _)| n == self.finish) {
+                self.longest(self.finish, visited, len + d, 0, best);
+                return;
+            }
+        }
+        // even taking the longest corridor of every junction left can not beat the best path
+        if best.is_some_and(|b| len + rest <= b) {
+            return;
+        }
+
+        let visited = visited | 1 << node;
+        let rest = rest - self.longest_edge(node);
+        for &(next, d) in self.edges[node].iter() {
+            if visited & 1 << next == 0 {
+                self.longest(next, visited, len + d, rest, best);
+            }
+        }
+    }
+
+    fn longest_edge(&self, node: usize) -> usize {
+        self.edges[node].iter().map(|&(_, d)| d).max().unwrap_or(0)
+    }
+}
+
+/// Length of the longest walk from the start to the finish never stepping on the same tile twice
+fn solve(map: &Grid<char>, slopes: bool) -> Result<usize> {
+    let start = Pos{x:1, y: 0};
+    let finish = Pos{x: map.width()-2, y: map.height()-1};
+
+    let junctions = Junctions::new(map, start, finish, slopes);
+    if junctions.edges.len() > u64::BITS as usize {
+        return Err(Error::unsolvable(format!("too many junctions: {}", junctions.edges.len())));
+    }
+
+    let rest = (0..junctions.edges.len()).map(|i| junctions.longest_edge(i)).sum();
+    let mut best = None;
+    junctions.longest(junctions.start, 0, 0, rest, &mut best);
+    best.ok_or_else(|| Error::unsolvable("there is no path to the finish"))
+}
+
+pub struct Puzzle {
+    map: Grid<char>,
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "A Long Walk";
+
+    fn parse(data: &str) -> Result<Self> {
+        let map = Grid::parse(lines(data), ".#>v")?;
+        let (w, h) = (map.width(), map.height());
+        if w < 3 || h < 2 || map[Pos{x: 1, y: 0}] != '.' || map[Pos{x: w-2, y: h-1}] != '.' {
+            return Err(Error::unsolvable("start and finish must be next to the top left and bottom right corners"));
+        }
+
+        Ok(Self{ map })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        solve(&self.map, true).map(Answer::from)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve(&self.map, false).map(Answer::from)
+    }
+}
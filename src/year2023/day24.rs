@@ -0,0 +1,195 @@
+use crate::solver::{Answer, Params, Solver};
+use crate::utils::{linalg, lines, BigInt, Error, Line, Point3, Rational, Result};
+
+type I = i128;
+
+type P3d = Point3<I>;
+
+/// Test area of the real input, used for both coordinates
+const AREA: (I, I) = (200000000000000, 400000000000000);
+/// Test area of the examples
+const EXAMPLE_AREA: (I, I) = (7, 27);
+/// Inputs with smaller coordinates are examples
+const EXAMPLE_LIMIT: I = 1000;
+
+#[derive(Debug)]
+struct Hailstone {
+    pos: P3d,
+    v: P3d,
+}
+
+impl Hailstone {
+    fn new(line: &Line) -> Result<Self> {
+        let (pos, v) = line.split_once("@")?;
+
+        Ok(Self{ pos: P3d::parse(line, pos)?, v: P3d::parse(line, v)? })
+    }
+
+    fn max_coord(&self) -> I {
+        [self.pos.x, self.pos.y, self.pos.z].into_iter().map(I::abs).max().unwrap()
+    }
+}
+
+/// Point where the future paths of hailstones `a` and `b` (0-based) cross in the XY plane
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crossing {
+    pub a: usize,
+    pub b: usize,
+    pub x: Rational,
+    pub y: Rational,
+}
+
+/// Exact crossing point of the XY paths inside the inclusive `area`, `None` for crossings in the past
+/// of either hailstone and for parallel paths, which have no single crossing point even when they overlap
+fn crossing_xy(h1: &Hailstone, h2: &Hailstone, area: (I, I)) -> Option<(Rational, Rational)> {
+    // h1.pos + h1.v * t1/d == h2.pos + h2.v * t2/d
+    let d = h1.v.x * h2.v.y - h1.v.y * h2.v.x;
+    if d == 0 {
+        return None;
+    }
+    let dp = h2.pos - h1.pos;
+    let t1 = dp.x * h2.v.y - dp.y * h2.v.x;
+    let t2 = dp.x * h1.v.y - dp.y * h1.v.x;
+    if t1.signum() * d.signum() < 0 || t2.signum() * d.signum() < 0 {
+        return None;
+    }
+
+    // compare numerators over the positive denominator before reducing the fractions
+    let (t1, d) = if d < 0 { (-t1, -d) } else { (t1, d) };
+    let (t1, d) = (BigInt::from(t1), BigInt::from(d));
+    let (min, max) = (BigInt::from(area.0) * d.clone(), BigInt::from(area.1) * d.clone());
+    let coord = |p: I, v: I| {
+        let n = BigInt::from(p) * d.clone() + BigInt::from(v) * t1.clone();
+        (min <= n && n <= max).then_some(n)
+    };
+    let (x, y) = (coord(h1.pos.x, h1.v.x)?, coord(h1.pos.y, h1.v.y)?);
+    Some((Rational::new(x, d.clone()), Rational::new(y, d)))
+}
+
+fn parse_area(s: &str) -> Result<(I, I)> {
+    let err = || Error::param("area", format!("expected MIN..MAX, got `{}`", s));
+    let (min, max) = s.split_once("..").ok_or_else(err)?;
+    let min: I = min.trim().parse().map_err(|_| err())?;
+    let max: I = max.trim().parse().map_err(|_| err())?;
+    if min > max {
+        return Err(err());
+    }
+    Ok((min, max))
+}
+
+/// Three equations of `P×(v_i - v_j) + (p_i - p_j)×V = p_i×v_i - p_j×v_j`, which is linear in
+/// the rock position `P` and velocity `V` once the `P×V` term of both hailstones cancels out
+fn pair_equations(a: &Hailstone, b: &Hailstone) -> [([I; 6], I); 3] {
+    let w = a.v - b.v;
+    let u = a.pos - b.pos;
+    let r = a.pos.cross(&a.v) - b.pos.cross(&b.v);
+    [
+        ([0, w.z, -w.y, 0, -u.z, u.y], r.x),
+        ([-w.z, 0, w.x, u.z, 0, -u.x], r.y),
+        ([w.y, -w.x, 0, -u.y, u.x, 0], r.z),
+    ]
+}
+
+fn throw_from(a: &Hailstone, b: &Hailstone, c: &Hailstone) -> Option<Hailstone> {
+    let (m, rhs): (Vec<_>, Vec<_>) = pair_equations(a, b)
+        .into_iter()
+        .chain(pair_equations(a, c))
+        .map(|(row, r)| (row.map(BigInt::from).to_vec(), BigInt::from(r)))
+        .unzip();
+    let x = linalg::solve(&m, &rhs)?
+        .iter()
+        .map(Rational::to_i128)
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Hailstone{ pos: P3d::new(x[0], x[1], x[2]), v: P3d::new(x[3], x[4], x[5]) })
+}
+
+fn hits(rock: &Hailstone, h: &Hailstone) -> bool {
+    let (dp, dv) = (h.pos - rock.pos, rock.v - h.v);
+    dp.cross(&dv) == P3d::default() && dp.dot(&dv) >= 0 && (dv != P3d::default() || dp == P3d::default())
+}
+
+fn solve2(h: &[Hailstone]) -> Result<I> {
+    if h.len() < 3 {
+        return Err(Error::unsolvable("at least 3 hailstones are needed"));
+    }
+    // stones with parallel velocities give dependent equations, so look for
+    // a triple that pins the throw down and check it against every stone
+    let n = h.len().min(10);
+    let rock = (0..n)
+        .flat_map(|i| (i+1..n).flat_map(move |j| (j+1..n).map(move |k| (i, j, k))))
+        .filter(|&(i, j, k)| {
+            h[i].v.cross(&h[j].v) != P3d::default() && h[i].v.cross(&h[k].v) != P3d::default()
+        })
+        .find_map(|(i, j, k)| throw_from(&h[i], &h[j], &h[k]))
+        .ok_or_else(|| Error::unsolvable("hailstone equations are degenerate"))?;
+
+    if let Some(i) = h.iter().position(|s| !hits(&rock, s)) {
+        return Err(Error::unsolvable(format!("no single throw hits every hailstone, hailstone {} is missed", i + 1)));
+    }
+
+    Ok(rock.pos.x + rock.pos.y + rock.pos.z)
+}
+
+pub struct Puzzle {
+    hailstones: Vec<Hailstone>,
+    /// Inclusive bounds of both coordinates of the test area
+    area: (I, I),
+    list_crossings: bool,
+}
+
+impl Puzzle {
+    /// Crossings of future paths inside the test area, in order of hailstones
+    pub fn crossings(&self) -> Vec<Crossing> {
+        let h = &self.hailstones;
+        (0..h.len())
+            .flat_map(|a| (a+1..h.len()).map(move |b| (a, b)))
+            .filter_map(|(a, b)| {
+                crossing_xy(&h[a], &h[b], self.area)
+                    .map(|(x, y)| Crossing{ a, b, x, y })
+            })
+            .collect()
+    }
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "Never Tell Me The Odds";
+
+    fn parse(data: &str) -> Result<Self> {
+        Self::parse_with(data, &Params::new())
+    }
+
+    /// `area=MIN..MAX` overrides the test area, which is otherwise picked by the size of the coordinates,
+    /// and the `crossings` flag lists the crossings of part 1 in its details
+    fn parse_with(data: &str, params: &Params) -> Result<Self> {
+        let hailstones = lines(data)
+            .map(|line| Hailstone::new(&line))
+            .collect::<Result<Vec<_>>>()?;
+        let area = match params.get("area") {
+            Some(s) => parse_area(s)?,
+            None if hailstones.iter().all(|h| h.max_coord() < EXAMPLE_LIMIT) => EXAMPLE_AREA,
+            None => AREA,
+        };
+
+        Ok(Self{ hailstones, area, list_crossings: params.flag("crossings") })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.crossings().len().into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        solve2(&self.hailstones).map(Answer::from)
+    }
+
+    fn details(&self, part: u8) -> Vec<String> {
+        if part != 1 || !self.list_crossings {
+            return Vec::new();
+        }
+        self.crossings()
+            .iter()
+            .map(|c| format!("hailstones {} and {} cross at x={}, y={}", c.a + 1, c.b + 1, c.x, c.y))
+            .collect()
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use crate::solver::{Answer, Params, Solver};
+use crate::utils::search::{bfs, Bfs};
+use crate::utils::{lines, Error, Result};
+
+type U = usize;
+
+/// Number of wires to disconnect
+const CUT: usize = 3;
+/// Wire of the start state of a search, which is not reached over any wire
+const NO_WIRE: U = U::MAX;
+
+/// Wires whose removal splits the components into two groups, and the sizes of the groups
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub wires: Vec<(String, String)>,
+    pub sizes: (usize, usize),
+}
+
+#[derive(Debug, Default)]
+struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, U>,
+    wires: Vec<(U, U)>,
+    /// `(neighbour, wire)` of every component
+    adj: Vec<Vec<(U, U)>>,
+}
+
+impl Graph {
+    fn node(&mut self, name: &str) -> U {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        self.names.push(name.to_string());
+        self.adj.push(Vec::new());
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    fn connect(&mut self, a: &str, b: &str) {
+        let (a, b) = (self.node(a), self.node(b));
+        let w = self.wires.len();
+        self.wires.push((a, b));
+        self.adj[a].push((b, w));
+        self.adj[b].push((a, w));
+    }
+
+    /// Components on the `s` side of a minimum cut between `s` and `t`, `None` if the cut has more than
+    /// `limit` wires. Every wire carries one unit of flow either way, so each augmenting path adds one wire.
+    fn min_cut(&self, s: U, t: U, limit: usize) -> Option<Vec<U>> {
+        // +1 runs along the wire from its first end, -1 backwards
+        let mut flow = vec![0i8; self.wires.len()];
+        for _ in 0..=limit {
+            let flow_ref = &flow;
+            let residual = move |&(u, _): &(U, U)| {
+                self.adj[u].iter().copied().filter(move |&(_, w)| {
+                    if self.wires[w].0 == u { flow_ref[w] < 1 } else { flow_ref[w] > -1 }
+                })
+            };
+
+            match bfs([(s, NO_WIRE)], |&(u, _)| u, residual, |&(u, _)| u == t) {
+                Some(found) => {
+                    for &(v, w) in found.path[1..].iter() {
+                        flow[w] += if self.wires[w].1 == v { 1 } else { -1 };
+                    }
+                }
+                None => return Some(Bfs::new([(s, NO_WIRE)], |&(u, _)| u, residual).map(|((u, _), _)| u).collect()),
+            }
+        }
+        None
+    }
+
+    fn three_cut(&self) -> Result<Cut> {
+        let neighbours = |&u: &U| self.adj[u].iter().map(|&(v, _)| v);
+        let order = Bfs::new([0], |&u| u, neighbours).map(|(u, _)| u).collect::<Vec<_>>();
+        if order.len() < self.names.len() {
+            return Err(Error::unsolvable("the components are split already"));
+        }
+
+        // the far end of the graph is most likely on the other side, but any component there will do
+        for &t in order.iter().skip(1).rev() {
+            let Some(side) = self.min_cut(0, t, CUT) else { continue };
+            let mut inside = vec![false; self.names.len()];
+            side.iter().for_each(|&u| inside[u] = true);
+
+            let wires = self.wires
+                .iter()
+                .filter(|&&(a, b)| inside[a] != inside[b])
+                .map(|&(a, b)| (self.names[a].clone(), self.names[b].clone()))
+                .collect::<Vec<_>>();
+            if wires.len() < CUT {
+                return Err(Error::unsolvable(format!("the components are split by {} wires", wires.len())));
+            }
+            return Ok(Cut{ wires, sizes: (side.len(), self.names.len() - side.len()) });
+        }
+        Err(Error::unsolvable(format!("no {} wires split the components", CUT)))
+    }
+}
+
+pub struct Puzzle {
+    graph: Graph,
+    show_cut: bool,
+}
+
+impl Puzzle {
+    pub fn cut(&self) -> Result<Cut> {
+        self.graph.three_cut()
+    }
+}
+
+impl Solver for Puzzle {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Snowverload";
+    const PARTS: u8 = 1;
+
+    fn parse(data: &str) -> Result<Self> {
+        Self::parse_with(data, &Params::new())
+    }
+
+    /// The `cut` flag adds the cut wires and the group sizes to the details
+    fn parse_with(data: &str, params: &Params) -> Result<Self> {
+        let mut graph = Graph::default();
+        for line in lines(data) {
+            let (name, others) = line.split_once(": ")?;
+            others.split_whitespace().for_each(|other| graph.connect(name, other));
+        }
+        if graph.names.len() < 2 {
+            return Err(Error::unsolvable("at least 2 components are needed"));
+        }
+
+        Ok(Self{ graph, show_cut: params.flag("cut") })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let cut = self.cut()?;
+        Ok((cut.sizes.0 * cut.sizes.1).into())
+    }
+
+    fn details(&self, _part: u8) -> Vec<String> {
+        let Some(cut) = self.show_cut.then(|| self.cut().ok()).flatten() else {
+            return Vec::new();
+        };
+        let wires = cut.wires.iter().map(|(a, b)| format!("{}/{}", a, b)).collect::<Vec<_>>();
+        vec![format!("cut wires {}, group sizes {} and {}", wires.join(", "), cut.sizes.0, cut.sizes.1)]
+    }
+}
@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use aoc::bench::{self, Stats};
+use aoc::registry;
+use aoc::solver::Params;
+
+#[test]
+fn stats_of_samples() {
+    let ms = |v: &[u64]| v.iter().map(|&x| Duration::from_millis(x)).collect::<Vec<_>>();
+
+    let s = Stats::new(ms(&[5, 1, 3]));
+    assert_eq!((s.min, s.median, s.max), (Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(5)));
+
+    let s = Stats::new(ms(&[4, 1, 2, 8]));
+    assert_eq!(s.median, Duration::from_millis(3));
+}
+
+#[test]
+fn bench_reports_every_stage() {
+    let day = registry::find(2023, 9).unwrap();
+    let stages = bench::bench(day, "0 3 6 9 12 15", &Params::new(), None, 3).unwrap();
+    let names = stages.iter().map(|s| s.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["parse", "part1", "part2"]);
+    assert!(stages.iter().all(|s| s.stats.min <= s.stats.median && s.stats.median <= s.stats.max));
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+use std::process::Command as Process;
+
+use aoc::cli::{self, Cli, Command, Options};
+use aoc::report::Format;
+
+fn parse(args: &str) -> Result<Cli, String> {
+    cli::parse(args.split_whitespace().map(String::from))
+}
+
+#[test]
+fn day_ranges() {
+    assert_eq!(cli::parse_days("5").unwrap(), [5]);
+    assert_eq!(cli::parse_days("1-3,15,20-22").unwrap(), [1, 2, 3, 15, 20, 21, 22]);
+    assert!(cli::parse_days("0").is_err());
+    assert!(cli::parse_days("26").is_err());
+    assert!(cli::parse_days("3-1").is_err());
+    assert!(cli::parse_days("1,,2").is_err());
+    assert!(cli::parse_days("x").is_err());
+
+    assert_eq!(parse("check 20-22 1,21").unwrap().command, Command::Check(vec![1, 20, 21, 22]));
+    assert_eq!(parse("bench").unwrap().command, Command::Bench(vec![]));
+}
+
+#[test]
+fn commands_and_options() {
+    assert_eq!(parse("run 5").unwrap(), Cli{ command: Command::Run(5), options: Options::default() });
+
+    let cli = parse("--format=json test 7 --part 2 -").unwrap();
+    assert_eq!(cli.command, Command::Test(7, PathBuf::from("-")));
+    assert_eq!(cli.options.format, Format::Json);
+    assert_eq!(cli.options.part, Some(2));
+
+    let cli = parse("check --record --jobs 0 --data-dir inputs --pattern in{day}.txt").unwrap();
+    assert!(cli.options.record);
+    assert_eq!(cli.options.jobs, 0);
+    assert_eq!(cli.options.data_dir, Some(PathBuf::from("inputs")));
+    assert_eq!(cli.options.pattern.as_deref(), Some("in{day}.txt"));
+
+    let cli = parse("test 24 in.txt --param area=7..27 --param=crossings --param area=0..5").unwrap();
+    assert_eq!(cli.options.params.get("area"), Some("0..5"));
+    assert!(cli.options.params.flag("crossings"));
+    assert!(!cli.options.params.flag("frob"));
+
+    assert_eq!(parse("run 5 --help").unwrap().command, Command::Help);
+    assert_eq!(parse("help").unwrap().command, Command::Help);
+}
+
+#[test]
+fn invalid_arguments() {
+    for args in [
+        "", "frob", "run", "run 26", "run 1 2", "test 5", "test x file", "check --part 3",
+        "check --format xml", "check --jobs", "bench --runs 0", "test 5 file --record", "check --frob",
+        "check --param", "check --param =5", "new-day 5 --param area=1..2",
+        "bench --format json", "new-day 5 --format csv",
+    ] {
+        assert!(parse(args).is_err(), "`{}` should be rejected", args);
+    }
+}
+
+#[test]
+fn missing_parts_fail_only_for_test() {
+    let aoc = |args: &[&str]| {
+        Process::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .unwrap()
+            .status
+            .code()
+    };
+    assert_eq!(aoc(&["check", "25", "--part", "2"]), Some(0));
+    assert_eq!(aoc(&["bench", "25", "--part", "2", "--runs", "1"]), Some(0));
+    assert_eq!(aoc(&["test", "25", "data/2023/day25.txt", "--part", "2"]), Some(1));
+}
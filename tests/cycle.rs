@@ -0,0 +1,47 @@
+use aoc::utils::cycle::{detect, hash, nth, nth_by_hash, Cycle};
+
+// 1 -> 2 -> 4 -> 8 -> ... -> 52 -> 4
+fn step(x: &mut u32) {
+    *x = *x * 2 % 100;
+}
+
+fn slow(n: usize) -> u32 {
+    let mut x = 1;
+    // the cycle starts at step 2 and has a period of 20
+    let n = if n < 2 { n } else { 2 + (n - 2) % 20 };
+    (0..n).for_each(|_| step(&mut x));
+    x
+}
+
+#[test]
+fn finds_start_and_period() {
+    assert_eq!(detect(&1, step, |&x| x), Cycle{ start: 2, period: 20 });
+    assert_eq!(detect(&1, step, hash), Cycle{ start: 2, period: 20 });
+    assert_eq!(detect(&4, step, |&x| x), Cycle{ start: 0, period: 20 });
+    assert_eq!(detect(&0, step, |&x| x), Cycle{ start: 0, period: 1 });
+
+    // a colliding key is not taken for a repeated state
+    assert_eq!(detect(&5u64, |x| *x = (*x + 1) % 21, |&x| x % 7), Cycle{ start: 0, period: 21 });
+}
+
+#[test]
+fn jumps_ahead() {
+    let c = Cycle{ start: 3, period: 5 };
+    assert_eq!((c.reduce(2), c.reduce(3), c.reduce(9), c.reduce(1000)), (2, 3, 4, 5));
+
+    for n in [0, 1, 2, 21, 22, 23, 1000] {
+        assert_eq!(nth(1, step, |&x| x, n), slow(n));
+        assert_eq!(nth_by_hash(1, step, n), slow(n));
+    }
+    assert_eq!(nth(1, step, |&x| x, 1_000_000_000), slow(1_000_000_000));
+    assert_eq!(nth_by_hash(1, step, 1_000_000_000), 76);
+}
+
+#[test]
+fn colliding_keys_are_confirmed() {
+    // most states share a key with an earlier one that is not the same state
+    for n in [0, 5, 21, 22, 23, 1000, 1_000_000_000] {
+        assert_eq!(nth(1, step, |&x| x % 3, n), slow(n));
+    }
+    assert_eq!(nth(0u64, |x| *x += 1, |&x| x % 7, 1_000), 1_000);
+}
@@ -0,0 +1,26 @@
+use std::fs;
+
+use aoc::manifest::{DataDir, DEFAULT_PATTERN};
+
+#[test]
+fn inputs_follow_the_pattern() {
+    let root = std::env::temp_dir().join(format!("aoc-data-dir-{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+    for name in ["day05.txt", "day05-alice.txt", "day05_t1.txt", "day050.txt", "day05.in", "in5.dat", "in5-bob.dat", "in15.dat"] {
+        fs::write(root.join(name), "").unwrap();
+    }
+
+    let names = |data: &DataDir, day| data.inputs(day).unwrap().into_iter().map(|(n, _)| n).collect::<Vec<_>>();
+
+    let data = DataDir::new(&root, DEFAULT_PATTERN).unwrap();
+    assert_eq!(data.file_name(5), "day05.txt");
+    assert_eq!(names(&data, 5), ["day05", "day05-alice", "day05_t1"]);
+
+    let data = DataDir::new(&root, "in{day}.dat").unwrap();
+    assert_eq!(names(&data, 5), ["in5", "in5-bob"]);
+    assert_eq!(names(&data, 15), ["in15"]);
+    assert!(names(&data, 1).is_empty());
+
+    assert!(DataDir::new(&root, "input.txt").is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,25 @@
+use aoc::utils::{Dir, Point};
+
+#[test]
+fn turns_and_deltas() {
+    assert_eq!(Dir::Up.turn_right(), Dir::Right);
+    assert_eq!(Dir::Up.turn_left(), Dir::Left);
+    assert_eq!(Dir::Left.opposite(), Dir::Right);
+    for d in Dir::ALL {
+        assert_eq!(d.turn_right().turn_left(), d);
+        assert_eq!(d.turn_right().turn_right(), d.opposite());
+        assert_eq!(d.delta::<i32>() + d.opposite().delta(), Point::default());
+        assert_eq!(d.is_vertical(), d.delta::<i32>().x == 0);
+    }
+    assert_eq!(Dir::Up.delta::<i64>(), Point{ x: 0, y: -1 });
+}
+
+#[test]
+fn parsing() {
+    for (s, d) in [("URDL", Dir::Up), ("^>v<", Dir::Right), ("NESW", Dir::Down)] {
+        let dirs = s.chars().map(|c| Dir::from_char(c).unwrap()).collect::<Vec<_>>();
+        assert_eq!(dirs, Dir::ALL);
+        assert!(dirs.contains(&d));
+    }
+    assert_eq!(Dir::from_char('x'), None);
+}
@@ -0,0 +1,55 @@
+use aoc::registry;
+use aoc::solver::Params;
+use aoc::utils::Error;
+
+fn parse_error(day: u8, data: &str) -> (usize, usize) {
+    match registry::find(2023, day).unwrap().parse(data) {
+        Err(Error::Parse{ line, col, .. }) => (line, col),
+        Err(e) => panic!("day {}: expected a parse error, got {}", day, e),
+        Ok(_) => panic!("day {}: malformed input was accepted", day),
+    }
+}
+
+#[test]
+fn malformed_inputs_point_at_the_error() {
+    assert_eq!(parse_error(2, "Game 1: 3 blue\nGame 2: 4 purple"), (2, 11));
+    assert_eq!(parse_error(5, "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50"), (5, 1));
+    assert_eq!(parse_error(7, "32T3K 765\nKK677 x"), (2, 7));
+    assert_eq!(parse_error(10, ".....\n.S-7.\n.|.|.\n.L-J.\n..*.."), (5, 3));
+    assert_eq!(parse_error(19, "in{s<1351:A,R}\n\n{x=787,m=2655,q=1222,s=2876}"), (3, 15));
+    assert_eq!(parse_error(22, "1,0,1~1,2,1\n0,0,2~2,0"), (2, 7));
+}
+
+#[test]
+fn inputs_without_an_answer_are_unsolvable() {
+    let day = registry::find(2023, 10).unwrap();
+    let solver = day.parse(".....\n.S-7.\n.|.|.\n.L-..\n.....").unwrap();
+    assert!(matches!(solver.part1(), Err(Error::Unsolvable(_))));
+
+    let day = registry::find(2023, 13).unwrap();
+    let solver = day.parse("#.#\n.#.\n##.").unwrap();
+    assert!(matches!(solver.part1(), Err(Error::Unsolvable(_))));
+
+    let day = registry::find(2023, 20).unwrap();
+    let solver = day.parse("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a").unwrap();
+    assert!(matches!(solver.part2(), Err(Error::Unsolvable(_))));
+    let solver = day.parse("broadcaster -> a\n%a -> rx").unwrap();
+    assert!(matches!(solver.part2(), Err(Error::Unsolvable(_))));
+
+    // the conjunction gets a low pulse only and never sends one
+    let solver = day.parse("broadcaster -> c\n&c -> rx").unwrap();
+    assert!(matches!(solver.part2(), Err(Error::Unsolvable(_))));
+
+    let day = registry::find(2023, 25).unwrap();
+    let solver = day.parse("a: b c\nb: c\nc: d").unwrap();
+    assert!(matches!(solver.part1(), Err(Error::Unsolvable(_))));
+    assert!(matches!(solver.part2(), Err(Error::Unsolvable(_))));
+}
+
+#[test]
+fn malformed_params_are_reported() {
+    let day = registry::find(2023, 24).unwrap();
+    let mut params = Params::new();
+    params.insert("area=27..7");
+    assert!(matches!(day.parse_with("19, 13, 30 @ -2,  1, -2", &params), Err(Error::Param{ .. })));
+}
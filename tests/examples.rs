@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::Path;
+
+use aoc::manifest::{self, Manifest};
+use aoc::registry;
+
+/// Runs every `data_test/YYYY/dayNN_tK.txt` example against `data_test/YYYY/answers.toml`
+#[test]
+fn examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("data_test");
+    let mut failures = Vec::new();
+
+    for entry in fs::read_dir(&root).expect("can not list example years") {
+        let dir = entry.unwrap().path();
+        let Some(year) = dir.file_name().and_then(|n| n.to_str()).and_then(|n| n.parse().ok()) else {
+            continue;
+        };
+        check_year(year, &dir, &mut failures);
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+fn check_year(year: u16, dir: &Path, failures: &mut Vec<String>) {
+    let manifest = Manifest::load(&dir.join("answers.toml")).expect("can not load examples manifest");
+
+    let mut names = fs::read_dir(dir)
+        .expect("can not list examples")
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("txt"))
+        .map(|p| p.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    names.sort();
+
+    for name in names.iter() {
+        let Some(day) = manifest::day_of(name).and_then(|d| registry::find(year, d)) else {
+            failures.push(format!("{}/{}: no registered day for the file name", year, name));
+            continue;
+        };
+        let Some(expected) = manifest.get(name) else {
+            failures.push(format!("{}/{}: no expected answers", year, name));
+            continue;
+        };
+
+        // a scaffolded day until its example is filled in
+        if (1..=day.parts).all(|p| expected.skip.contains(&p)) {
+            continue;
+        }
+
+        let data = fs::read_to_string(dir.join(format!("{}.txt", name))).unwrap();
+        let solver = match day.parse(data.trim_end()) {
+            Ok(solver) => solver,
+            Err(e) => {
+                failures.push(format!("{}/{}: {}", year, name, e));
+                continue;
+            }
+        };
+        for part in 1..=day.parts {
+            if expected.skip.contains(&part) {
+                continue;
+            }
+            let Some(exp) = expected.part(part) else {
+                failures.push(format!("{}/{}: no expected answer for part {}", year, name, part));
+                continue;
+            };
+            match if part == 1 { solver.part1() } else { solver.part2() } {
+                Ok(ans) if ans != *exp => {
+                    failures.push(format!("{}/{}: part {} returned {}, expected {}", year, name, part, ans, exp));
+                }
+                Ok(_) => {}
+                Err(e) => failures.push(format!("{}/{}: part {}: {}", year, name, part, e)),
+            }
+        }
+    }
+
+    for name in manifest.names().filter(|n| !names.iter().any(|x| x == n)) {
+        failures.push(format!("{}/{}: expected answers for a missing file", year, name));
+    }
+}
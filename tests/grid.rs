@@ -0,0 +1,50 @@
+use aoc::utils::{lines, Error, Grid, Point};
+
+fn grid() -> Grid<char> {
+    Grid::parse(lines("abc\ndef"), "abcdef").unwrap()
+}
+
+#[test]
+fn parsing_and_access() {
+    let g = grid();
+    assert_eq!((g.width(), g.height()), (3, 2));
+    assert_eq!(g[Point{ x: 2, y: 1 }], 'f');
+    assert_eq!(g.get(Point{ x: -1, y: 0 }), None);
+    assert_eq!(g.get(Point{ x: 3usize, y: 0 }), None);
+    assert_eq!(g.wrapped(Point{ x: -1, y: 3 }), Some(&'f'));
+    assert_eq!(Grid::new(0, 0, 'a').wrapped(Point{ x: 1, y: 1 }), None);
+    assert_eq!(g.find(&'e'), Some(Point{ x: 1, y: 1 }));
+    assert_eq!(g.positions(|&c| c > 'b').collect::<Vec<Point<i32>>>().len(), 4);
+    assert_eq!(g.to_string(), "abc\ndef");
+
+    assert!(matches!(Grid::parse(lines("ab\nabc"), "abc"), Err(Error::Parse{ line: 2, .. })));
+    assert!(matches!(Grid::parse(lines("ab\nax"), "ab"), Err(Error::Parse{ line: 2, col: 2, .. })));
+    assert!(matches!(Grid::parse(lines(""), "ab"), Err(Error::Parse{ line: 1, .. })));
+    assert!(matches!(Grid::parse(Vec::new(), "ab"), Err(Error::Parse{ line: 1, col: 1, .. })));
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+}
+
+#[test]
+fn neighbours_stay_inside() {
+    let g = grid();
+    let n4 = g.neighbours4(Point{ x: 0, y: 0 }).collect::<Vec<_>>();
+    assert_eq!(n4, [Point{ x: 1, y: 0 }, Point{ x: 0, y: 1 }]);
+    assert_eq!(g.neighbours4(Point{ x: 1, y: 1 }).count(), 3);
+    assert_eq!(g.neighbours8(Point{ x: 1usize, y: 0 }).count(), 5);
+}
+
+#[test]
+fn rows_columns_and_rotations() {
+    let g = grid();
+    assert_eq!(g.row(1), ['d', 'e', 'f']);
+    assert_eq!(g.column(1).collect::<String>(), "be");
+    assert_eq!(g.transposed().to_string(), "ad\nbe\ncf");
+    assert_eq!(g.rotated_cw().to_string(), "da\neb\nfc");
+    assert_eq!(g.rotated_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(g.rotated_cw().rotated_ccw(), g);
+
+    let mut g = g.map(|&c| c as u8 - b'a');
+    g[Point{ x: 0, y: 0 }] = 9;
+    assert_eq!(g.to_string(), "912\n345");
+    assert_eq!(Grid::new(2, 1, 0).to_string(), "00");
+}
@@ -0,0 +1,60 @@
+use aoc::utils::{Interval, IntervalBox, IntervalSet, RangeMap};
+
+fn set(v: &[(i32, i32)]) -> IntervalSet<i32> {
+    v.iter().map(|&(s, e)| Interval::new(s, e)).collect()
+}
+
+#[test]
+fn intervals() {
+    let i = Interval::new(2, 7);
+    assert_eq!(i.len(), 5);
+    assert!(i.contains(2) && !i.contains(7));
+    assert_eq!(Interval::inclusive(1u64, 4000).len(), 4000);
+    assert_eq!(Interval::new(5, 3).len(), 0);
+    assert!(i.intersection(&Interval::new(7, 9)).is_empty());
+    assert_eq!(i.split_at(4), (Interval::new(2, 4), Interval::new(4, 7)));
+    assert_eq!(i.split_at(10), (i, Interval::new(7, 7)));
+}
+
+#[test]
+fn set_operations() {
+    let a = set(&[(5, 8), (0, 2), (2, 3), (10, 12)]);
+    assert_eq!(a.intervals(), [Interval::new(0, 3), Interval::new(5, 8), Interval::new(10, 12)]);
+    assert_eq!(a.len(), 8);
+    assert_eq!(a.min(), Some(0));
+    assert!(a.contains(7) && !a.contains(8) && !a.contains(-1));
+    assert_eq!(set(&[(0, 10), (3, 4)]), set(&[(0, 10)]));
+
+    let b = set(&[(1, 6), (11, 20)]);
+    assert_eq!(a.union(&b), set(&[(0, 8), (10, 20)]));
+    assert_eq!(a.intersection(&b), set(&[(1, 3), (5, 6), (11, 12)]));
+    assert_eq!(a.difference(&b), set(&[(0, 1), (6, 8), (10, 11)]));
+    assert_eq!(b.difference(&a), set(&[(3, 5), (12, 20)]));
+    assert_eq!(a.difference(&a), IntervalSet::new());
+    assert_eq!(a.split_at(6), (set(&[(0, 3), (5, 6)]), set(&[(6, 8), (10, 12)])));
+}
+
+#[test]
+fn range_maps() {
+    // seed-to-soil map of the day 5 example
+    let mut m = RangeMap::new();
+    m.insert(Interval::with_len(98u64, 2), 50);
+    m.insert(Interval::with_len(50, 48), 52);
+    assert_eq!((m.get(98), m.get(53), m.get(10)), (50, 55, 10));
+
+    let seeds = [Interval::with_len(79, 14), Interval::with_len(55, 13)].into_iter().collect();
+    assert_eq!(m.apply(&seeds).intervals(), [Interval::new(57, 70), Interval::new(81, 95)]);
+    let all = IntervalSet::from(Interval::new(0, 100));
+    assert_eq!(m.apply(&all), all);
+}
+
+#[test]
+fn boxes() {
+    let b = IntervalBox::new([Interval::inclusive(1u64, 4000); 4]);
+    assert_eq!(b.volume(), 256_000_000_000_000);
+
+    let (lo, hi) = b.split_at(1, 1001);
+    assert_eq!(lo.unwrap().volume() + hi.unwrap().volume(), b.volume());
+    assert_eq!(lo.unwrap().dims[1], Interval::new(1, 1001));
+    assert_eq!(b.split_at(0, 1), (None, Some(b)));
+}
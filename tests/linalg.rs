@@ -0,0 +1,77 @@
+use aoc::utils::linalg::solve;
+use aoc::utils::{BigInt, Rational};
+
+fn big(v: i128) -> BigInt {
+    BigInt::from(v)
+}
+
+fn matrix(rows: &[&[i128]]) -> Vec<Vec<BigInt>> {
+    rows.iter().map(|r| r.iter().map(|&v| big(v)).collect()).collect()
+}
+
+#[test]
+fn bigint_arithmetic() {
+    let a = big(i128::MAX);
+    let b = big(i128::MIN);
+    let p = a.clone() * b.clone();
+    assert_eq!(p.to_string(), "-28948022309329048855892746252171976963147354982949671778132708698262398304256");
+    assert_eq!(p.clone() / b.clone(), a);
+    assert!((p % a.clone()).is_zero());
+
+    assert_eq!(big(-7) / big(2), big(-3));
+    assert_eq!(big(-7) % big(2), big(-1));
+    assert_eq!(big(7) - big(10), big(-3));
+    assert_eq!(big(-12).gcd(&big(18)), big(6));
+    assert!(big(-5) < big(3) && big(-5) < big(-4));
+
+    assert_eq!(i128::try_from(&(a.clone() + big(0))), Ok(i128::MAX));
+    assert_eq!(i128::try_from(&b), Ok(i128::MIN));
+    assert!(i128::try_from(&(a + big(1))).is_err());
+}
+
+#[test]
+fn rational_arithmetic() {
+    let half = Rational::new(big(2), big(-4));
+    assert_eq!(half.to_string(), "-1/2");
+    assert_eq!(half.denom(), &big(2));
+
+    let third = Rational::new(big(1), big(3));
+    assert_eq!((half.clone() + third.clone()).to_string(), "-1/6");
+    assert_eq!((half.clone() * third.clone()).to_string(), "-1/6");
+    assert_eq!((third.clone() / half.clone()).to_string(), "-2/3");
+    assert_eq!((half.clone() - half.clone()), Rational::from(0));
+    assert!(half < third);
+
+    assert_eq!(Rational::new(big(6), big(3)).to_i128(), Some(2));
+    assert_eq!(third.to_i128(), None);
+}
+
+#[test]
+fn solve_exact() {
+    let a = matrix(&[&[2, 1, -1], &[-3, -1, 2], &[-2, 1, 2]]);
+    let b = [8, -11, -3].map(big);
+    let x = solve(&a, &b).unwrap();
+    assert_eq!(x, [2, 3, -1].map(Rational::from));
+
+    // zero on the diagonal needs a row swap, the solution is fractional
+    let a = matrix(&[&[0, 2], &[3, 1]]);
+    let b = [1, 1].map(big);
+    let x = solve(&a, &b).unwrap();
+    assert_eq!(x.iter().map(|v| v.to_string()).collect::<Vec<_>>(), ["1/6", "1/2"]);
+}
+
+#[test]
+fn solve_large_coefficients() {
+    // values far beyond f64 precision
+    let n = 200_000_000_000_000i128;
+    let a = matrix(&[&[n, 1], &[1, n]]);
+    let b = [n * 3 + 7, 3 + n * 7].map(big);
+    assert_eq!(solve(&a, &b).unwrap(), [3, 7].map(Rational::from));
+}
+
+#[test]
+fn solve_singular() {
+    let a = matrix(&[&[1, 2], &[2, 4]]);
+    assert_eq!(solve(&a, &[big(1), big(2)]), None);
+    assert_eq!(solve(&[], &[]), Some(vec![]));
+}
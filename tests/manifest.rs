@@ -0,0 +1,22 @@
+use aoc::manifest::Manifest;
+use aoc::solver::Answer;
+
+#[test]
+fn comments_end_outside_of_strings() {
+    let text = "\
+# answers
+[day13_t1] # the example
+part1 = \"#.#\" # a comment after the answer
+part2 = 400 # another one
+skip = \"part1\" #
+";
+    let manifest = Manifest::parse(text).unwrap();
+    let expected = manifest.get("day13_t1").unwrap();
+    assert_eq!(expected.part1, Some(Answer::from("#.#")));
+    assert_eq!(expected.part2, Some(Answer::from(400)));
+    assert_eq!(expected.skip, [1]);
+
+    // written back and parsed again unchanged
+    let again = Manifest::parse(&manifest.to_string()).unwrap();
+    assert_eq!(again.get("day13_t1"), Some(expected));
+}
@@ -0,0 +1,43 @@
+use aoc::utils::num::{crt, ext_gcd, gcd, lcm, lcm_all, mod_inv, mod_mul, mod_pow};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(gcd(12u64, 18), 6);
+    assert_eq!(gcd(0u32, 7), 7);
+    assert_eq!(gcd(0i32, 0), 0);
+    assert_eq!(gcd(-12i64, 18), 6);
+
+    assert_eq!(lcm(4u64, 6), Some(12));
+    assert_eq!(lcm(0u64, 6), Some(0));
+    assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+    assert_eq!(lcm(u64::MAX, 2), None);
+    assert_eq!(lcm_all([4027u64, 4001, 3877, 3907]), Some(244055946148853));
+    assert_eq!(lcm_all(Vec::<u32>::new()), Some(1));
+}
+
+#[test]
+fn modular_arithmetic() {
+    let (g, x, y) = ext_gcd(240i64, 46);
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+
+    assert_eq!(mod_inv(3i32, 11), Some(4));
+    assert_eq!(mod_inv(-3i32, 11), Some(7));
+    assert_eq!(mod_inv(4i32, 8), None);
+
+    assert_eq!(mod_pow(4u32, 13, 497), 445);
+    assert_eq!(mod_pow(2u64, 0, 1), 0);
+    let m = u64::MAX - 58;
+    assert_eq!(mod_mul(m - 1, m - 1, m), 1);
+    assert_eq!(mod_pow(m - 1, 3, m), m - 1);
+}
+
+#[test]
+fn chinese_remainders() {
+    assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(3i64, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(crt([(1i64, 4), (2, 6)]), None);
+    assert_eq!(crt([(0i64, 4027), (0, 4001), (0, 3877), (0, 3907)]), Some((0, 244055946148853)));
+    assert_eq!(crt([(-1i128, 1 << 100), (0, 3)]), Some(((1 << 100) - 1, 3 << 100)));
+    assert_eq!(crt([(-1i128, 1 << 100), (1, 3)]), Some(((1 << 101) - 1, 3 << 100)));
+}
@@ -0,0 +1,42 @@
+use aoc::utils::Point;
+
+#[test]
+fn arithmetic() {
+    let (p, q) = (Point::new(3, -2), Point::new(-1, 5));
+    assert_eq!(p + q, Point::new(2, 3));
+    assert_eq!(p - q, Point::new(4, -7));
+    assert_eq!(p * 3, Point::new(9, -6));
+    assert_eq!(p / 2, Point::new(1, -1));
+    assert_eq!(-p, Point::new(-3, 2));
+
+    let mut r = p;
+    r += q;
+    r -= Point::new(1, 1);
+    r *= 2;
+    assert_eq!(r, Point::new(2, 4));
+}
+
+#[test]
+fn distances_and_products() {
+    let (p, q) = (Point::new(1, 2), Point::new(4, -2));
+    assert_eq!(p.manhattan(&q), 7);
+    assert_eq!(p.chebyshev(&q), 4);
+    assert_eq!(Point::new(5usize, 1).manhattan(&Point::new(2, 3)), 5);
+    assert_eq!(p.dot(&q), 0);
+    assert_eq!(p.cross(&q), -10);
+    assert_eq!(q.norm2(), 20);
+}
+
+#[test]
+fn rotations_and_conversions() {
+    let up = Point::new(0, -1);
+    assert_eq!(up.rot90_cw(), Point::new(1, 0));
+    assert_eq!(up.rot90_ccw(), Point::new(-1, 0));
+    assert_eq!(up.rot90_cw().rot90_cw().rot90_cw().rot90_cw(), up);
+
+    assert_eq!(Point::from((1, 2)), Point::new(1, 2));
+    assert_eq!(<(i32, i32)>::from(Point::new(1, 2)), (1, 2));
+    assert_eq!(Point::new(1i32, -2).cast::<i64>(), Point::new(1i64, -2));
+    assert_eq!(Point::new(1i64, 2).try_cast::<usize>(), Some(Point::new(1usize, 2)));
+    assert_eq!(Point::new(-1i64, 2).try_cast::<usize>(), None);
+}
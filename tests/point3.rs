@@ -0,0 +1,47 @@
+use aoc::utils::{lines, Cuboid, Error, Point3};
+
+#[test]
+fn arithmetic_and_products() {
+    let (p, q) = (Point3::new(1, -2, 3), Point3::new(4, 0, -1));
+    assert_eq!(p + q, Point3::new(5, -2, 2));
+    assert_eq!(p - q, Point3::new(-3, -2, 4));
+    assert_eq!(p * 2, Point3::new(2, -4, 6));
+    assert_eq!(-p, Point3::new(-1, 2, -3));
+    assert_eq!(p.manhattan(&q), 9);
+    assert_eq!(p.dot(&q), 1);
+    assert_eq!(p.cross(&q), Point3::new(2, 13, 8));
+    assert_eq!(p.cross(&q).dot(&p), 0);
+
+    let mut r = p;
+    r += q;
+    r *= 3;
+    assert_eq!(r, Point3::new(15, -6, 6));
+}
+
+#[test]
+fn parsing() {
+    let line = lines("1,0,1~19, 13, -30").next().unwrap();
+    let (a, b) = line.split_once("~").unwrap();
+    assert_eq!(Point3::<u32>::parse(&line, a).unwrap(), Point3::new(1, 0, 1));
+    assert_eq!(Point3::<i64>::parse(&line, b).unwrap(), Point3::new(19, 13, -30));
+    assert!(matches!(Point3::<u32>::parse(&line, b), Err(Error::Parse{ col: 14, .. })));
+    assert!(matches!(Point3::<u32>::parse(&line, &a[2..]), Err(Error::Parse{ col: 3, .. })));
+}
+
+#[test]
+fn cuboids() {
+    let c = Cuboid::new(Point3::new(2, 0, 1), Point3::new(0, 1, 1));
+    assert_eq!(c.min, Point3::new(0, 0, 1));
+    assert_eq!(c.volume(), 6);
+    assert_eq!(c.points().count(), 6);
+    assert!(c.contains(Point3::new(2, 1, 1)));
+    assert!(!c.contains(Point3::new(2, 1, 2)));
+
+    let d = Cuboid::new(Point3::new(1, 1, 0), Point3::new(5, 5, 5));
+    assert_eq!(c.intersection(&d), Some(Cuboid::new(Point3::new(1, 1, 1), Point3::new(2, 1, 1))));
+    assert!(!c.intersects(&(d + Point3::new(0, 0, 10))));
+
+    let t = Cuboid::new(Point3::new(0u32, 0, 1), Point3::new(0, 0, 4));
+    assert_eq!(t.top(), Cuboid::new(Point3::new(0, 0, 4), Point3::new(0, 0, 4)));
+    assert_eq!(t.bottom().volume(), 1);
+}
@@ -0,0 +1,51 @@
+use aoc::utils::{Dir, Point, Polygon};
+
+fn square() -> Polygon {
+    Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(4, 4), Point::new(0, 4)])
+}
+
+#[test]
+fn areas_and_lattice_points() {
+    let s = square();
+    assert_eq!(s.double_area(), 32);
+    assert_eq!(Polygon::new(s.vertices().iter().rev().copied().collect()).double_area(), -32);
+    assert_eq!(s.boundary(), 16);
+    assert_eq!(s.interior(), 9);
+    assert_eq!(s.lattice_points(), 25);
+
+    let t = Polygon::new(vec![Point::new(0, 0), Point::new(4, 2), Point::new(0, 2)]);
+    assert_eq!(t.double_area(), 8);
+    assert_eq!(t.boundary(), 8);
+    assert_eq!(t.interior(), 1);
+    assert_eq!(Polygon::new(vec![]).lattice_points(), 0);
+}
+
+#[test]
+fn built_from_moves() {
+    // the lagoon of the day 18 example
+    let moves = [
+        ('R', 6), ('D', 5), ('L', 2), ('D', 2), ('R', 2), ('D', 2), ('L', 5),
+        ('U', 2), ('L', 1), ('U', 2), ('R', 2), ('U', 3), ('L', 2), ('U', 2),
+    ];
+    let p = Polygon::from_moves(moves.map(|(d, n)| (Dir::from_char(d).unwrap(), n)));
+    assert_eq!(p.vertices()[..3], [Point::new(0, 0), Point::new(6, 0), Point::new(6, 5)]);
+    assert_eq!(p.boundary(), 38);
+    assert_eq!(p.lattice_points(), 62);
+}
+
+#[test]
+fn point_in_polygon() {
+    let s = square();
+    assert_eq!(s.winding_number(Point::new(1, 3)), -1);
+    assert_eq!(s.winding_number(Point::new(5, 1)), 0);
+    assert!(s.contains(Point::new(2, 2)));
+    assert!(!s.contains(Point::new(4, 2)));
+    assert!(s.on_boundary(Point::new(4, 2)));
+    assert!(!s.on_boundary(Point::new(5, 0)));
+
+    // an L shape has a notch which is outside
+    let l = Polygon::from_moves([(Dir::Right, 4), (Dir::Down, 2), (Dir::Left, 2), (Dir::Down, 2), (Dir::Left, 2), (Dir::Up, 4)]);
+    assert!(l.contains(Point::new(1, 3)));
+    assert!(!l.contains(Point::new(3, 3)));
+    assert_eq!((1..4).flat_map(|x| (1..4).map(move |y| Point::new(x, y))).filter(|&p| l.contains(p)).count() as i64, l.interior());
+}
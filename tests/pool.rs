@@ -0,0 +1,31 @@
+use std::thread;
+use std::time::Duration;
+
+use aoc::pool;
+
+#[test]
+fn results_come_in_input_order() {
+    // later items finish first
+    let items = (0..8u64).collect::<Vec<_>>();
+    let mut seen = Vec::new();
+    let res: Result<(), ()> = pool::map_ordered(items, 4, |i| {
+        thread::sleep(Duration::from_millis(5 * (8 - i)));
+        i * i
+    }, |r| {
+        seen.push(r);
+        Ok(())
+    });
+    assert_eq!(res, Ok(()));
+    assert_eq!(seen, [0, 1, 4, 9, 16, 25, 36, 49]);
+}
+
+#[test]
+fn sink_error_stops_the_run() {
+    let mut seen = Vec::new();
+    let res = pool::map_ordered((0..100).collect(), 3, |i| i, |r| {
+        seen.push(r);
+        if r == 5 { Err(r) } else { Ok(()) }
+    });
+    assert_eq!(res, Err(5));
+    assert_eq!(seen, (0..=5).collect::<Vec<_>>());
+}
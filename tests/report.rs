@@ -0,0 +1,77 @@
+use aoc::manifest::Manifest;
+use aoc::registry;
+use aoc::report::{Format, Printer, Report, Status};
+use aoc::solver::Params;
+
+const DATA: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45";
+
+fn render(format: Format, report: &Report) -> String {
+    let mut out = Vec::new();
+    let mut printer = Printer::new(format, &mut out);
+    printer.begin().unwrap();
+    printer.print(report).unwrap();
+    printer.end().unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn report() -> Report {
+    let manifest = Manifest::parse("[day09_t1]\npart1 = 114\npart2 = 3").unwrap();
+    Report::solve(registry::find(2023, 9).unwrap(), "day09_t1", DATA, &Params::new(), None, manifest.get("day09_t1"))
+}
+
+#[test]
+fn statuses_follow_expectations() {
+    let r = report();
+    assert_eq!(r.parts.iter().map(|p| p.status).collect::<Vec<_>>(), [Status::Ok, Status::Wrong]);
+    assert_eq!(r.error.as_deref(), Some("wrong answer at day 9 part 2: got 2, expected 3"));
+
+    let r = Report::solve(registry::find(2023, 9).unwrap(), "day09_t1", DATA, &Params::new(), None, None);
+    assert!(r.is_ok());
+    assert!(r.parts.iter().all(|p| p.status == Status::Unchecked));
+}
+
+#[test]
+fn csv_has_a_row_per_part() {
+    let csv = render(Format::Csv, &report());
+    let rows = csv.lines().map(|l| l.split(',').collect::<Vec<_>>()).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0][..7], ["year", "day", "input", "part", "answer", "expected", "status"]);
+    assert_eq!(rows[1][..7], ["2023", "9", "day09_t1", "1", "114", "114", "ok"]);
+    assert_eq!(rows[2][..7], ["2023", "9", "day09_t1", "2", "2", "3", "wrong"]);
+}
+
+#[test]
+fn json_is_an_array_of_records() {
+    let json = render(Format::Json, &Report::failed(2023, 9, "day09", "bad \"input\""));
+    assert_eq!(json.lines().next(), Some("["));
+    assert!(json.contains("\"part\": null"));
+    assert!(json.contains("\"status\": \"error\""));
+    assert!(json.contains("\"error\": \"bad \\\"input\\\"\""));
+    assert!(json.trim_end().ends_with(']'));
+}
+
+#[test]
+fn details_are_reported_per_part() {
+    let data = "19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4";
+    let mut params = Params::new();
+    params.insert("crossings");
+    let r = Report::solve(registry::find(2023, 24).unwrap(), "day24_t1", data, &params, Some(1), None);
+    assert_eq!(r.parts[0].details, ["hailstones 1 and 2 cross at x=43/3, y=46/3", "hailstones 1 and 3 cross at x=35/3, y=50/3"]);
+    assert!(r.text().contains("\n  hailstones 1 and 2 cross"));
+    assert!(render(Format::Json, &r).contains("\"details\": \"hailstones 1 and 2 cross at x=43/3, y=46/3\\nhailstones"));
+
+    let r = Report::solve(registry::find(2023, 24).unwrap(), "day24_t1", data, &Params::new(), Some(1), None);
+    assert!(r.parts[0].details.is_empty());
+}
+
+#[test]
+fn days_without_the_part_are_skipped() {
+    let day = registry::find(2023, 25).unwrap();
+    assert!(day.has_part(None) && day.has_part(Some(1)) && !day.has_part(Some(2)));
+
+    let r = Report::skipped(2023, 25, "day25", 2);
+    assert!(r.is_ok());
+    assert_eq!(r.text(), "Day 25:\nDay 25 has no part 2, skipped\n");
+    let csv = render(Format::Csv, &r);
+    assert_eq!(csv.lines().nth(1), Some("2023,25,day25,,,,skipped,,,,"));
+}
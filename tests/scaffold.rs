@@ -0,0 +1,102 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use aoc::manifest::{DataDir, DEFAULT_PATTERN};
+use aoc::scaffold::{new_day, Template};
+
+#[test]
+fn new_day_is_registered_and_never_overwritten() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    fs::create_dir_all(root.join("src/year2023")).unwrap();
+    fs::create_dir_all(root.join("data_test/2023")).unwrap();
+    fs::write(root.join("src/lib.rs"), "pub mod utils;\n\nyears! {\n    year2023,\n}\n").unwrap();
+    fs::write(root.join("src/year2023/mod.rs"), "pub const YEAR: u16 = 2023;\n\ndays! {\n    day01,\n    day03,\n}\n").unwrap();
+    fs::write(root.join("src/year2023/day01.rs"), "").unwrap();
+    fs::write(root.join("data_test/2023/answers.toml"), "# examples\n").unwrap();
+    let data = DataDir::new(root.join("data"), DEFAULT_PATTERN).unwrap();
+
+    let changed = new_day(&root, &data.year(2023), 2023, 2, Template::Grid).unwrap();
+    assert_eq!(changed.len(), 5);
+    assert_eq!(
+        fs::read_to_string(root.join("src/year2023/mod.rs")).unwrap(),
+        "pub const YEAR: u16 = 2023;\n\ndays! {\n    day01,\n    day02,\n    day03,\n}\n"
+    );
+    let module = fs::read_to_string(root.join("src/year2023/day02.rs")).unwrap();
+    assert!(module.contains("const DAY: u8 = 2;") && module.contains("Grid::parse"));
+    assert!(root.join("data/2023/day02.txt").is_file());
+    assert!(root.join("data_test/2023/day02_t1.txt").is_file());
+    assert_eq!(
+        fs::read_to_string(root.join("data_test/2023/answers.toml")).unwrap(),
+        "# examples\n\n[day02_t1]\n# TODO: fill in the example and its answers\nskip = \"part1, part2\"\n"
+    );
+
+    fs::write(root.join("src/year2023/day02.rs"), "solved").unwrap();
+    assert!(new_day(&root, &data.year(2023), 2023, 2, Template::Lines).is_err());
+    assert_eq!(fs::read_to_string(root.join("src/year2023/day02.rs")).unwrap(), "solved");
+    // registered without a module
+    assert!(new_day(&root, &data.year(2023), 2023, 3, Template::Lines).is_err());
+    assert!(!root.join("src/year2023/day03.rs").exists());
+
+    // the first day of a new year registers the year as well
+    new_day(&root, &data.year(2024), 2024, 1, Template::Lines).unwrap();
+    assert_eq!(
+        fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+        "pub mod utils;\n\nyears! {\n    year2023,\n    year2024,\n}\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join("src/year2024/mod.rs")).unwrap(),
+        "pub const YEAR: u16 = 2024;\n\ndays! {\n    day01,\n}\n"
+    );
+    assert!(root.join("data/2024/day01.txt").is_file());
+    assert!(root.join("data_test/2024/day01_t1.txt").is_file());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+/// Scaffolds a day of every template in a copy of this crate, runs its examples test and checks
+/// the new days on their empty inputs
+#[test]
+fn scaffolded_crate_passes_examples() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("aoc-scaffold-crate-{}", std::process::id()));
+    for dir in ["src", "templates", "data_test"] {
+        copy_dir(&crate_dir.join(dir), &root.join(dir));
+    }
+    fs::create_dir_all(root.join("tests")).unwrap();
+    fs::copy(crate_dir.join("tests/examples.rs"), root.join("tests/examples.rs")).unwrap();
+    fs::copy(crate_dir.join("Cargo.toml"), root.join("Cargo.toml")).unwrap();
+
+    let data = DataDir::new(root.join("data"), DEFAULT_PATTERN).unwrap();
+    new_day(&root, &data.year(2024), 2024, 1, Template::Lines).unwrap();
+    new_day(&root, &data.year(2024), 2024, 2, Template::Grid).unwrap();
+    new_day(&root, &data.year(2024), 2024, 3, Template::Sections).unwrap();
+
+    let cargo = |args: &[&str]| {
+        let output = Command::new(env!("CARGO"))
+            .args(args)
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", root.join("target"))
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+    };
+    cargo(&["test", "--offline", "--test", "examples"]);
+    // the empty inputs of the new days are parsed as well
+    cargo(&["run", "--offline", "--", "check", "--year", "2024"]);
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,68 @@
+use aoc::utils::search::{astar, bfs, dijkstra, Bfs};
+use aoc::utils::{lines, Grid, Point};
+
+type Pos = Point<i32>;
+
+fn maze() -> Grid<char> {
+    Grid::parse(lines("S.#.\n.##.\n...E"), "S.#E").unwrap()
+}
+
+fn open(g: &Grid<char>, p: Pos) -> Vec<Pos> {
+    g.neighbours4(p).filter(|&n| g[n] != '#').collect()
+}
+
+#[test]
+fn breadth_first() {
+    let g = maze();
+    let (s, e): (Pos, Pos) = (g.find(&'S').unwrap(), g.find(&'E').unwrap());
+
+    let found = bfs([s], |p| *p, |&p| open(&g, p), |&p| p == e).unwrap();
+    assert_eq!(found.cost, 5);
+    assert_eq!(found.path.len(), 6);
+    assert_eq!((found.path[0], found.path[5]), (s, e));
+    assert!(found.path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+
+    assert!(bfs([s], |p| *p, |&p| open(&g, p), |&p| p == Point::new(3, 0)).is_some());
+    assert!(bfs([s], |p| *p, |&p| open(&g, p), |&p| p == Point::new(2, 0)).is_none());
+
+    let order = Bfs::new([s], |p| *p, |&p| open(&g, p)).map(|(_, d)| d).collect::<Vec<_>>();
+    assert_eq!(order.len(), 9);
+    assert!(order.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(Bfs::new([s], |p| *p, |&p| open(&g, p)).take_while(|&(_, d)| d <= 2).count(), 4);
+}
+
+#[test]
+fn weighted() {
+    // the direct edge is more expensive than the detour
+    let edges = |&n: &u8| match n {
+        0 => vec![(1, 10), (2, 1)],
+        2 => vec![(3, 1)],
+        3 => vec![(1, 1)],
+        _ => vec![],
+    };
+    let found = dijkstra([0u8], |n| *n, edges, |&n| n == 1).unwrap();
+    assert_eq!(found, aoc::utils::search::Found{ cost: 3, path: vec![0, 2, 3, 1] });
+    assert!(dijkstra([0u8], |n| *n, edges, |&n| n == 9).is_none());
+}
+
+#[test]
+fn keyed_states() {
+    // walking right along a line, after two steps in a row a step back is needed
+    let target = 5;
+    let moves = |&(x, run): &(i32, u8)| {
+        let mut res = Vec::new();
+        if x > -3 {
+            res.push(((x - 1, 0), 1));
+        }
+        if run < 2 {
+            res.push(((x + 1, run + 1), 1));
+        }
+        res
+    };
+    let found = astar([(0, 0)], |s| *s, moves, |&(x, _)| (target - x).abs(), |&(x, _)| x == target).unwrap();
+    assert_eq!(found.cost, 11);
+
+    // keyed on the position alone the run limit is forgotten between visits
+    let found = astar([(0, 0)], |&(x, _)| x, moves, |&(x, _)| (target - x).abs(), |&(x, _)| x == target);
+    assert!(found.is_none());
+}